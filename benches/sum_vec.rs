@@ -0,0 +1,74 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use sum::{Sum4, SumVec4};
+
+const LEN: u64 = 100_000;
+
+type Element = Sum4<u8, u32, u64, [u64; 8]>;
+
+fn element(index: u64) -> Element {
+	match index % 16 {
+		0 => Sum4::D([index; 8]),
+		1..=5 => Sum4::C(index),
+		6..=10 => Sum4::B(index as u32),
+		_ => Sum4::A(index as u8),
+	}
+}
+
+fn total(sum: Sum4<&u8, &u32, &u64, &[u64; 8]>) -> u64 {
+	match sum {
+		Sum4::A(a) => u64::from(*a),
+		Sum4::B(b) => u64::from(*b),
+		Sum4::C(c) => *c,
+		Sum4::D(d) => d.iter().sum(),
+	}
+}
+
+fn push(c: &mut Criterion) {
+	let mut group = c.benchmark_group("push");
+	let _ = group.bench_function("Vec<Sum4>", |b| b.iter(|| (0..LEN).map(element).collect::<Vec<_>>()));
+	let _ = group.bench_function("SumVec4", |b| b.iter(|| (0..LEN).map(element).collect::<SumVec4<_, _, _, _>>()));
+	group.finish();
+}
+
+fn iter(c: &mut Criterion) {
+	let vec: Vec<Element> = (0..LEN).map(element).collect();
+	let sum_vec: SumVec4<_, _, _, _> = (0..LEN).map(element).collect();
+	let mut group = c.benchmark_group("iter");
+	let _ = group.bench_function("Vec<Sum4>", |b| b.iter(|| black_box(&vec).iter().map(|sum| total(sum.as_ref())).sum::<u64>()));
+	let _ = group.bench_function("SumVec4", |b| b.iter(|| black_box(&sum_vec).iter().map(total).sum::<u64>()));
+	let _ = group.bench_function("SumVec4 slices", |b| {
+		b.iter(|| {
+			let (a, b, c, d) = black_box(&sum_vec).as_slices();
+			a.iter().map(|&a| u64::from(a)).sum::<u64>()
+				+ b.iter().map(|&b| u64::from(b)).sum::<u64>()
+				+ c.iter().sum::<u64>()
+				+ d.iter().flatten().sum::<u64>()
+		})
+	});
+	group.finish();
+}
+
+fn retain(c: &mut Criterion) {
+	let vec: Vec<Element> = (0..LEN).map(element).collect();
+	let sum_vec: SumVec4<_, _, _, _> = (0..LEN).map(element).collect();
+	let mut group = c.benchmark_group("retain");
+	let _ = group.bench_function("Vec<Sum4>", |b| {
+		b.iter(|| {
+			let mut vec = vec.clone();
+			vec.retain(|sum| total(sum.as_ref()) % 3 != 0);
+			vec
+		})
+	});
+	let _ = group.bench_function("SumVec4", |b| {
+		b.iter(|| {
+			let mut sum_vec = sum_vec.clone();
+			sum_vec.retain(|sum| total(sum) % 3 != 0);
+			sum_vec
+		})
+	});
+	group.finish();
+}
+
+criterion_group!(benches, push, iter, retain);
+criterion_main!(benches);
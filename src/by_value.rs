@@ -0,0 +1,245 @@
+use std::{
+	borrow::Borrow, cmp::Ordering, fmt::{Debug, Formatter, Result}, hash::{Hash, Hasher}, marker::PhantomData
+};
+
+/// Borrows whichever variant is active as a `K`.
+///
+/// It's implemented for `SumN` when every variant implements `Borrow<K>`. `Sum2<String, Box<str>>` implements `BorrowInner<str>`, for example.
+///
+/// `SumN` can't implement `Borrow<K>` itself, as that would overlap with the blanket `impl<T> Borrow<T> for T`. [`ByValue`] does instead, which is what lets a `HashMap` keyed by sums be queried with a `&K`.
+pub trait BorrowInner<K: ?Sized> {
+	fn borrow_inner(&self) -> &K;
+}
+
+/// Wraps a sum to compare, order and hash it by the value of its active variant, borrowed as `K`, ignoring which variant it is.
+///
+/// The derived impls on `SumN` treat `Sum2::A(x)` and `Sum2::B(x)` as different, and order every `A` before every `B`. With `ByValue`, `Sum2<String, Box<str>>` values holding the same text are equal whichever variant holds it, and sort alphabetically:
+///
+/// ```
+/// use std::collections::HashSet;
+/// use sum::{ByValue, Sum2};
+///
+/// type Name = ByValue<Sum2<String, Box<str>>, str>;
+/// let names: HashSet<Name> = vec![
+///     ByValue::new(Sum2::A(String::from("alice"))),
+///     ByValue::new(Sum2::B("alice".into())),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(names.len(), 1);
+/// ```
+///
+/// As the comparisons and hash all go through `K`'s, they agree with each other whenever `K`'s do.
+///
+/// Every variant must borrow as the same `K`. Variants that instead convert to a common owned type, such as `u32` and `u64` to `u64`, can be compared by it with [`ByKey`].
+pub struct ByValue<S, K: ?Sized> {
+	sum: S,
+	marker: PhantomData<fn(&K)>,
+}
+
+impl<S, K: ?Sized> ByValue<S, K> {
+	pub fn new(sum: S) -> Self {
+		ByValue {
+			sum,
+			marker: PhantomData,
+		}
+	}
+	pub fn get_ref(&self) -> &S {
+		&self.sum
+	}
+	pub fn into_inner(self) -> S {
+		self.sum
+	}
+}
+impl<S, K: ?Sized> From<S> for ByValue<S, K> {
+	fn from(sum: S) -> Self {
+		ByValue::new(sum)
+	}
+}
+
+/// `ByValue` hashes and compares exactly as `K` does, as `Borrow` requires, so maps and sets keyed by it can be queried with a `&K`.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use sum::{ByValue, Sum2};
+///
+/// let mut ages: HashMap<ByValue<Sum2<String, Box<str>>, str>, u32> = HashMap::new();
+/// let _ = ages.insert(ByValue::new(Sum2::A(String::from("alice"))), 30);
+/// let _ = ages.insert(ByValue::new(Sum2::B("bob".into())), 25);
+/// assert_eq!(ages.get("bob"), Some(&25));
+/// ```
+impl<S, K: ?Sized> Borrow<K> for ByValue<S, K>
+where
+	S: BorrowInner<K>,
+{
+	fn borrow(&self) -> &K {
+		self.sum.borrow_inner()
+	}
+}
+
+impl<S, K: ?Sized> PartialEq for ByValue<S, K>
+where
+	S: BorrowInner<K>,
+	K: PartialEq,
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.sum.borrow_inner() == other.sum.borrow_inner()
+	}
+}
+impl<S, K: ?Sized> Eq for ByValue<S, K>
+where
+	S: BorrowInner<K>,
+	K: Eq,
+{
+}
+impl<S, K: ?Sized> PartialOrd for ByValue<S, K>
+where
+	S: BorrowInner<K>,
+	K: PartialOrd,
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		self.sum.borrow_inner().partial_cmp(other.sum.borrow_inner())
+	}
+}
+impl<S, K: ?Sized> Ord for ByValue<S, K>
+where
+	S: BorrowInner<K>,
+	K: Ord,
+{
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.sum.borrow_inner().cmp(other.sum.borrow_inner())
+	}
+}
+impl<S, K: ?Sized> Hash for ByValue<S, K>
+where
+	S: BorrowInner<K>,
+	K: Hash,
+{
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.sum.borrow_inner().hash(state);
+	}
+}
+impl<S, K: ?Sized> Clone for ByValue<S, K>
+where
+	S: Clone,
+{
+	fn clone(&self) -> Self {
+		ByValue::new(self.sum.clone())
+	}
+}
+impl<S, K: ?Sized> Copy for ByValue<S, K> where S: Copy {}
+impl<S, K: ?Sized> Debug for ByValue<S, K>
+where
+	S: Debug,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result {
+		f.debug_tuple("ByValue").field(&self.sum).finish()
+	}
+}
+
+/// Converts a clone of whichever variant is active into a `K`.
+///
+/// It's implemented for `SumN` when every variant is `Clone` and converts `Into<K>`. `Sum2<u32, u64>` implements `ToKey<u64>`, for example.
+pub trait ToKey<K> {
+	fn to_key(&self) -> K;
+}
+
+/// Wraps a sum to compare, order and hash it by the value of its active variant converted into the owned type `K`, ignoring which variant it is.
+///
+/// This is [`ByValue`] for variants that share no type to borrow as, but convert into a common one. With `ByKey`, `Sum2<u32, u64>` values holding the same number are equal whichever variant holds it, and sort numerically:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use sum::{ByKey, Sum2};
+///
+/// let mut totals: HashMap<ByKey<Sum2<u32, u64>, u64>, u32> = HashMap::new();
+/// *totals.entry(ByKey::new(Sum2::A(7))).or_default() += 1;
+/// *totals.entry(ByKey::new(Sum2::B(7))).or_default() += 1;
+/// assert_eq!(totals.len(), 1);
+/// ```
+///
+/// Each comparison and hash converts a clone of the active variant, so this suits cheap conversions such as between integer widths. As the key is owned, `ByKey` doesn't implement `Borrow<K>`.
+pub struct ByKey<S, K> {
+	sum: S,
+	marker: PhantomData<fn() -> K>,
+}
+
+impl<S, K> ByKey<S, K> {
+	pub fn new(sum: S) -> Self {
+		ByKey {
+			sum,
+			marker: PhantomData,
+		}
+	}
+	pub fn get_ref(&self) -> &S {
+		&self.sum
+	}
+	pub fn into_inner(self) -> S {
+		self.sum
+	}
+}
+impl<S, K> From<S> for ByKey<S, K> {
+	fn from(sum: S) -> Self {
+		ByKey::new(sum)
+	}
+}
+
+impl<S, K> PartialEq for ByKey<S, K>
+where
+	S: ToKey<K>,
+	K: PartialEq,
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.sum.to_key() == other.sum.to_key()
+	}
+}
+impl<S, K> Eq for ByKey<S, K>
+where
+	S: ToKey<K>,
+	K: Eq,
+{
+}
+impl<S, K> PartialOrd for ByKey<S, K>
+where
+	S: ToKey<K>,
+	K: PartialOrd,
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		self.sum.to_key().partial_cmp(&other.sum.to_key())
+	}
+}
+impl<S, K> Ord for ByKey<S, K>
+where
+	S: ToKey<K>,
+	K: Ord,
+{
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.sum.to_key().cmp(&other.sum.to_key())
+	}
+}
+impl<S, K> Hash for ByKey<S, K>
+where
+	S: ToKey<K>,
+	K: Hash,
+{
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.sum.to_key().hash(state);
+	}
+}
+impl<S, K> Clone for ByKey<S, K>
+where
+	S: Clone,
+{
+	fn clone(&self) -> Self {
+		ByKey::new(self.sum.clone())
+	}
+}
+impl<S, K> Copy for ByKey<S, K> where S: Copy {}
+impl<S, K> Debug for ByKey<S, K>
+where
+	S: Debug,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result {
+		f.debug_tuple("ByKey").field(&self.sum).finish()
+	}
+}
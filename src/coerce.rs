@@ -0,0 +1,63 @@
+use std::{
+	any::Any, error::Error, fmt::{Debug, Display}
+};
+
+/// Implemented by a trait object type `dyn Trait` for every `T: Trait`, so that `SumN::as_dyn`, `as_dyn_mut` and `into_dyn` can coerce whichever variant is active.
+///
+/// It's implemented for `dyn Any`, `dyn Debug`, `dyn Display` and `dyn Error`, alone and with `+ Send` or `+ Send + Sync`. [`impl_coerce_from!`](macro.impl_coerce_from.html) implements it for other traits.
+pub trait CoerceFrom<T> {
+	fn coerce_ref(from: &T) -> &Self;
+	fn coerce_mut(from: &mut T) -> &mut Self;
+	fn coerce_box(from: Box<T>) -> Box<Self>;
+}
+
+/// Implements [`CoerceFrom`] for a trait object type, letting sums whose variants all implement the trait be viewed as it.
+///
+/// ```
+/// # use sum::*;
+/// trait Shape {
+///     fn area(&self) -> f64;
+/// }
+/// impl_coerce_from!(dyn Shape);
+///
+/// struct Square(f64);
+/// impl Shape for Square {
+///     fn area(&self) -> f64 { self.0 * self.0 }
+/// }
+/// struct Circle(f64);
+/// impl Shape for Circle {
+///     fn area(&self) -> f64 { 3.0 * self.0 * self.0 }
+/// }
+///
+/// let shape = Sum2::<Square, Circle>::B(Circle(1.0));
+/// assert_eq!(shape.as_dyn::<dyn Shape>().area(), 3.0);
+/// ```
+#[macro_export]
+macro_rules! impl_coerce_from {
+	(dyn $($bound:tt)+) => {
+		impl<'a, T: $($bound)+ + 'a> $crate::CoerceFrom<T> for dyn $($bound)+ + 'a {
+			fn coerce_ref(from: &T) -> &Self {
+				from
+			}
+			fn coerce_mut(from: &mut T) -> &mut Self {
+				from
+			}
+			fn coerce_box(from: Box<T>) -> Box<Self> {
+				from
+			}
+		}
+	};
+}
+
+impl_coerce_from!(dyn Any);
+impl_coerce_from!(dyn Any + Send);
+impl_coerce_from!(dyn Any + Send + Sync);
+impl_coerce_from!(dyn Debug);
+impl_coerce_from!(dyn Debug + Send);
+impl_coerce_from!(dyn Debug + Send + Sync);
+impl_coerce_from!(dyn Display);
+impl_coerce_from!(dyn Display + Send);
+impl_coerce_from!(dyn Display + Send + Sync);
+impl_coerce_from!(dyn Error);
+impl_coerce_from!(dyn Error + Send);
+impl_coerce_from!(dyn Error + Send + Sync);
@@ -0,0 +1,273 @@
+#[cfg(feature = "futures")]
+use futures_core::stream::Stream;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+	error::Error, fmt::{Display, Formatter, Result}, future::Future, pin::Pin, task::{Context, Poll}
+};
+
+/// A sum type of `A` and the alternatives in `B`, terminated by [`Nil`].
+///
+/// `Cons<A, Cons<B, Cons<C, Nil>>>` holds the same values as `Sum3<A, B, C>`, and converts to and from it with `From`. Unlike `SumN` it nests arbitrarily deep, so it isn't bound by the 32-type limit. [`Cons!`](macro.Cons.html) spells out the nested type.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Cons<A, B> {
+	Head(A),
+	Tail(B),
+}
+
+/// The empty sum type that terminates a chain of [`Cons`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Nil {}
+
+/// Expands to the nested [`Cons`] type with as many variants as types given.
+///
+/// `Cons![u8, String, bool]` is `Cons<u8, Cons<String, Cons<bool, Nil>>>`.
+#[macro_export]
+macro_rules! Cons {
+	() => ($crate::Nil);
+	($first:ty $(, $t:ty)* $(,)?) => ($crate::Cons<$first, $crate::Cons![$($t),*]>);
+}
+
+impl<A, B> Cons<A, B> {
+	pub fn is_head(&self) -> bool {
+		match *self {
+			Cons::Head(_) => true,
+			Cons::Tail(_) => false,
+		}
+	}
+	pub fn is_tail(&self) -> bool {
+		!self.is_head()
+	}
+	pub fn head(self) -> Option<A> {
+		match self {
+			Cons::Head(a) => Some(a),
+			Cons::Tail(_) => None,
+		}
+	}
+	pub fn tail(self) -> Option<B> {
+		match self {
+			Cons::Head(_) => None,
+			Cons::Tail(b) => Some(b),
+		}
+	}
+	pub fn as_ref(&self) -> Cons<&A, &B> {
+		match *self {
+			Cons::Head(ref a) => Cons::Head(a),
+			Cons::Tail(ref b) => Cons::Tail(b),
+		}
+	}
+	pub fn as_mut(&mut self) -> Cons<&mut A, &mut B> {
+		match *self {
+			Cons::Head(ref mut a) => Cons::Head(a),
+			Cons::Tail(ref mut b) => Cons::Tail(b),
+		}
+	}
+	pub fn as_pin_ref(self: Pin<&Self>) -> Cons<Pin<&A>, Pin<&B>> {
+		unsafe {
+			match *self.get_ref() {
+				Cons::Head(ref a) => Cons::Head(Pin::new_unchecked(a)),
+				Cons::Tail(ref b) => Cons::Tail(Pin::new_unchecked(b)),
+			}
+		}
+	}
+	pub fn as_pin_mut(self: Pin<&mut Self>) -> Cons<Pin<&mut A>, Pin<&mut B>> {
+		unsafe {
+			match *self.get_unchecked_mut() {
+				Cons::Head(ref mut a) => Cons::Head(Pin::new_unchecked(a)),
+				Cons::Tail(ref mut b) => Cons::Tail(Pin::new_unchecked(b)),
+			}
+		}
+	}
+}
+
+impl<A, B> Error for Cons<A, B>
+where
+	A: Error,
+	B: Error,
+{
+	#[allow(deprecated)]
+	fn description(&self) -> &str {
+		match *self {
+			Cons::Head(ref inner) => inner.description(),
+			Cons::Tail(ref inner) => inner.description(),
+		}
+	}
+	#[allow(deprecated)]
+	fn cause(&self) -> Option<&dyn Error> {
+		match *self {
+			Cons::Head(ref inner) => inner.cause(),
+			Cons::Tail(ref inner) => inner.cause(),
+		}
+	}
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Cons::Head(ref inner) => inner.source(),
+			Cons::Tail(ref inner) => inner.source(),
+		}
+	}
+}
+impl<A, B> Display for Cons<A, B>
+where
+	A: Display,
+	B: Display,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result {
+		match *self {
+			Cons::Head(ref inner) => inner.fmt(f),
+			Cons::Tail(ref inner) => inner.fmt(f),
+		}
+	}
+}
+impl Error for Nil {}
+impl Display for Nil {
+	fn fmt(&self, _f: &mut Formatter) -> Result {
+		match *self {}
+	}
+}
+
+// The delegations with an associated type are implemented separately for the last cell, `Cons<A, Nil>`, and for the cells before it, as `Nil` has no `Item` or `Output` to agree with.
+
+impl<A> Iterator for Cons<A, Nil>
+where
+	A: Iterator,
+{
+	type Item = A::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match *self {
+			Cons::Head(ref mut inner) => inner.next(),
+			Cons::Tail(ref nil) => match *nil {},
+		}
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match *self {
+			Cons::Head(ref inner) => inner.size_hint(),
+			Cons::Tail(ref nil) => match *nil {},
+		}
+	}
+}
+impl<A, B, C> Iterator for Cons<A, Cons<B, C>>
+where
+	A: Iterator,
+	Cons<B, C>: Iterator<Item = A::Item>,
+{
+	type Item = A::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match *self {
+			Cons::Head(ref mut inner) => inner.next(),
+			Cons::Tail(ref mut inner) => inner.next(),
+		}
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match *self {
+			Cons::Head(ref inner) => inner.size_hint(),
+			Cons::Tail(ref inner) => inner.size_hint(),
+		}
+	}
+}
+impl<A> DoubleEndedIterator for Cons<A, Nil>
+where
+	A: DoubleEndedIterator,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match *self {
+			Cons::Head(ref mut inner) => inner.next_back(),
+			Cons::Tail(ref nil) => match *nil {},
+		}
+	}
+}
+impl<A, B, C> DoubleEndedIterator for Cons<A, Cons<B, C>>
+where
+	A: DoubleEndedIterator,
+	Cons<B, C>: DoubleEndedIterator<Item = A::Item>,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match *self {
+			Cons::Head(ref mut inner) => inner.next_back(),
+			Cons::Tail(ref mut inner) => inner.next_back(),
+		}
+	}
+}
+impl<A> ExactSizeIterator for Cons<A, Nil>
+where
+	A: ExactSizeIterator,
+{
+	fn len(&self) -> usize {
+		match *self {
+			Cons::Head(ref inner) => inner.len(),
+			Cons::Tail(ref nil) => match *nil {},
+		}
+	}
+}
+impl<A, B, C> ExactSizeIterator for Cons<A, Cons<B, C>>
+where
+	A: ExactSizeIterator,
+	Cons<B, C>: ExactSizeIterator<Item = A::Item>,
+{
+	fn len(&self) -> usize {
+		match *self {
+			Cons::Head(ref inner) => inner.len(),
+			Cons::Tail(ref inner) => inner.len(),
+		}
+	}
+}
+
+impl<A> Future for Cons<A, Nil>
+where
+	A: Future,
+{
+	type Output = A::Output;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+		match self.as_pin_mut() {
+			Cons::Head(inner) => inner.poll(cx),
+			Cons::Tail(nil) => match *nil {},
+		}
+	}
+}
+impl<A, B, C> Future for Cons<A, Cons<B, C>>
+where
+	A: Future,
+	Cons<B, C>: Future<Output = A::Output>,
+{
+	type Output = A::Output;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+		match self.as_pin_mut() {
+			Cons::Head(inner) => inner.poll(cx),
+			Cons::Tail(inner) => inner.poll(cx),
+		}
+	}
+}
+#[cfg(feature = "futures")]
+impl<A> Stream for Cons<A, Nil>
+where
+	A: Stream,
+{
+	type Item = A::Item;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+		match self.as_pin_mut() {
+			Cons::Head(inner) => inner.poll_next(cx),
+			Cons::Tail(nil) => match *nil {},
+		}
+	}
+}
+#[cfg(feature = "futures")]
+impl<A, B, C> Stream for Cons<A, Cons<B, C>>
+where
+	A: Stream,
+	Cons<B, C>: Stream<Item = A::Item>,
+{
+	type Item = A::Item;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+		match self.as_pin_mut() {
+			Cons::Head(inner) => inner.poll_next(cx),
+			Cons::Tail(inner) => inner.poll_next(cx),
+		}
+	}
+}
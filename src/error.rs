@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// Displays a sum prefixed with the name of its active variant, as returned by `SumN::display_with_variant`.
+///
+/// `Sum2::<ParseIntError, io::Error>::B(err).display_with_variant()` displays as `B: ` followed by `err`.
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayWithVariant<'a, S> {
+	pub(crate) variant: &'static str,
+	pub(crate) sum: &'a S,
+}
+
+impl<S> Display for DisplayWithVariant<'_, S>
+where
+	S: Display,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result {
+		write!(f, "{}: {}", self.variant, self.sum)
+	}
+}
+
+/// Wraps a sum of errors so that the active variant's error is its `source()`, rather than being displayed in its place.
+///
+/// Its `Display` names the variant, along with an optional context message, so error reporters that walk the `source()` chain show both which variant failed and why:
+///
+/// ```text
+/// loading config, in variant B of Sum2
+/// caused by: invalid digit found in string
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SumError<S> {
+	sum: S,
+	context: Option<String>,
+}
+
+impl<S> SumError<S> {
+	pub fn new(sum: S) -> Self {
+		SumError { sum, context: None }
+	}
+	pub fn with_context<Context>(sum: S, context: Context) -> Self
+	where
+		Context: Into<String>,
+	{
+		SumError {
+			sum,
+			context: Some(context.into()),
+		}
+	}
+	pub fn context(&self) -> Option<&str> {
+		self.context.as_deref()
+	}
+	pub fn get_ref(&self) -> &S {
+		&self.sum
+	}
+	pub fn into_inner(self) -> S {
+		self.sum
+	}
+}
+
+impl<S> From<S> for SumError<S> {
+	fn from(sum: S) -> Self {
+		SumError::new(sum)
+	}
+}
+
+/// Declares a newtype over a sum of distinct error types, convertible from each of them with `?`.
+///
+/// `error_union!(pub MainError(io::Error, ParseIntError));` declares `pub struct MainError(pub Sum2<io::Error, ParseIntError>)`, with:
+///
+/// * `From<io::Error>` and `From<ParseIntError>`, wrapping the error in its variant;
+/// * `Debug`, `Display` and `Error` delegating to the active variant's error, so `main` returning `Result<(), MainError>` reports the inner error rather than `A(Os { .. })`;
+/// * `From<MainError>` for the underlying sum.
+///
+/// ```no_run
+/// # use sum::error_union;
+/// use std::{fs, io, num::ParseIntError};
+///
+/// error_union!(pub MainError(io::Error, ParseIntError));
+///
+/// fn main() -> Result<(), MainError> {
+///     let count: u64 = fs::read_to_string("count.txt")?.trim().parse()?;
+///     println!("{}", count);
+///     Ok(())
+/// }
+/// ```
+///
+/// The types must be distinct, as otherwise it would be ambiguous which variant `?` converts into. Repeating one fails to compile:
+///
+/// ```compile_fail
+/// # use sum::error_union;
+/// error_union!(Ambiguous(std::num::ParseIntError, std::num::ParseIntError));
+/// ```
+#[macro_export]
+macro_rules! error_union {
+	($(#[$attr:meta])* $vis:vis $name:ident($($t:ty),+ $(,)?) $(;)?) => {
+		$(#[$attr])*
+		$vis struct $name(pub $crate::Sum![$($t),+]);
+
+		$crate::__error_union_from!($name [$($t),+] [] $($t,)+);
+
+		impl ::std::fmt::Debug for $name {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+				::std::fmt::Debug::fmt(&$crate::fmt::Transparent(&self.0), f)
+			}
+		}
+		impl ::std::fmt::Display for $name {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+				::std::fmt::Display::fmt(&self.0, f)
+			}
+		}
+		impl ::std::error::Error for $name {
+			fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+				::std::error::Error::source(&self.0)
+			}
+		}
+		impl ::std::convert::From<$name> for $crate::Sum![$($t),+] {
+			fn from(error: $name) -> Self {
+				error.0
+			}
+		}
+	};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __error_union_from {
+	($name:ident [$($all:ty),+] [$($before:tt)*]) => {};
+	($name:ident [$($all:ty),+] [$($before:tt)*] $t:ty, $($rest:ty,)*) => {
+		impl ::std::convert::From<$t> for $name {
+			fn from(error: $t) -> Self {
+				let cons: $crate::Cons![$($all),+] = $crate::__error_union_from!(@wrap [$($before)*] error);
+				$name(cons.into())
+			}
+		}
+		$crate::__error_union_from!($name [$($all),+] [$($before)* $t] $($rest,)*);
+	};
+	(@wrap [] $error:expr) => ($crate::Cons::Head($error));
+	(@wrap [$skip:tt $($before:tt)*] $error:expr) => ($crate::Cons::Tail($crate::__error_union_from!(@wrap [$($before)*] $error)));
+}
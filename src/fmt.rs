@@ -0,0 +1,7 @@
+//! Formatting helpers for sum types.
+
+/// Formats a sum with `Debug` as its active variant alone, without the variant's name.
+///
+/// `format!("{:?}", Transparent(Sum2::<u8, &str>::B("x")))` is `"x"`, where without the wrapper it's `B("x")`. It's implemented for `SumN` and `&SumN`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Transparent<S>(pub S);
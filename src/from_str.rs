@@ -0,0 +1,62 @@
+use std::{
+	error::Error, fmt::{Debug, Display, Formatter, Result}
+};
+
+/// The error returned by `SumN::from_str` when no variant parses, holding a tuple of every variant's error.
+///
+/// `"x".parse::<Sum2<u8, bool>>()` fails with `FromStrError((ParseIntError, ParseBoolError))`, displayed as:
+///
+/// ```text
+/// string did not match any variant: A: invalid digit found in string; B: provided string was not `true` or `false`
+/// ```
+///
+/// `Debug`, `Display` and `Error` are implemented for every arity, including those above 12 whose tuples don't implement `Debug`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FromStrError<E>(pub E);
+
+/// The error returned by `SumN::from_str_strict`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum StrictFromStrError<E> {
+	/// No variant parsed.
+	NoMatch(FromStrError<E>),
+	/// More than one variant parsed; holds the names of those that did.
+	Ambiguous(Vec<&'static str>),
+}
+
+impl<E> Debug for StrictFromStrError<E>
+where
+	FromStrError<E>: Debug,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result {
+		match *self {
+			StrictFromStrError::NoMatch(ref inner) => f.debug_tuple("NoMatch").field(inner).finish(),
+			StrictFromStrError::Ambiguous(ref variants) => {
+				f.debug_tuple("Ambiguous").field(variants).finish()
+			}
+		}
+	}
+}
+impl<E> Display for StrictFromStrError<E>
+where
+	FromStrError<E>: Display,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result {
+		match *self {
+			StrictFromStrError::NoMatch(ref inner) => inner.fmt(f),
+			StrictFromStrError::Ambiguous(ref variants) => {
+				write!(f, "string matched more than one variant: {}", variants.join(", "))
+			}
+		}
+	}
+}
+impl<E> Error for StrictFromStrError<E>
+where
+	FromStrError<E>: Error + 'static,
+{
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			StrictFromStrError::NoMatch(ref inner) => Some(inner),
+			StrictFromStrError::Ambiguous(_) => None,
+		}
+	}
+}
@@ -6,7 +6,13 @@
 //!
 //! Arbitrarily-sized product types exist in Rust in the form of [tuples](https://doc.rust-lang.org/std/primitive.tuple.html). This is a generalisation of bluss's [Either](https://docs.rs/either/1.5.0/either/enum.Either.html) type to provide **arbitrarily-sized sum types**\*.
 //!
-//! \* Over up to 32 types.
+//! \* Over up to 32 types. Beyond that, [`Cons`](enum@Cons) and [`Nil`] nest into sum types of any size.
+//!
+//! [`packed`] stores sums of pointers in a single tagged pointer, and [`repr_c`](repr_c/index.html) has counterparts of each `SumN` with a stable `#[repr(C, u8)]` layout, for FFI and shared memory.
+//!
+//! The arities compiled are chosen with the `max-8`, `max-16` and `max-32` features, the last of which is on by default, so a crate that only needs smaller sums can opt out with `default-features = false, features = ["max-8"]`. The numbered features `"0"` to `"32"` are still accepted; each enables its arity and all those below it.
+//!
+//! Further API on every `SumN` is opt-in, as it adds to the build time of each arity: `ops` implements the operator traits, `fmt` the formatting traits besides `Debug` and `Display`, and `from-str` `FromStr` and `from_str_strict`. `call` adds `call` and `into_fn`, `any` adds `from_any` and `as_any`, and `dyn` adds `as_dyn` and `CoerceFrom`. `repr-c` enables the `repr_c` module and `sum-vec` the `SumVecN` collections.
 
 #![doc(html_root_url = "https://docs.rs/sum/0.1.7")]
 #![warn(
@@ -22,7 +28,7 @@
 )] // from https://github.com/rust-unofficial/patterns/blob/master/anti_patterns/deny-warnings.md
 #![allow(unused_variables, unreachable_patterns)]
 #![allow(
-	clippy::empty_enum,
+	clippy::empty_enums,
 	clippy::type_complexity,
 	clippy::wrong_self_convention,
 	clippy::must_use_candidate,
@@ -33,11 +39,37 @@
 #[cfg(feature = "futures")]
 use futures_core::stream::Stream;
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use ::serde::{Deserialize, Serialize};
+#[cfg(feature = "any")]
+use std::any::{Any, TypeId};
+#[cfg(feature = "ops")]
+use std::ops;
+#[cfg(feature = "from-str")]
+use std::str::FromStr;
 use std::{
-	error::Error, fmt::{Display, Formatter, Result}, future::Future, hint::unreachable_unchecked, ops::{Deref, DerefMut}, pin::Pin, task::{Context, Poll}
+	borrow::{Borrow, Cow}, error::Error, fmt::{Debug, Display, Formatter, Result}, future::Future, marker::PhantomData, mem, ops::{Deref, DerefMut}, pin::Pin, task::{Context, Poll}
 };
 
+mod by_value;
+#[cfg(feature = "dyn")]
+mod coerce;
+mod cons;
+mod error;
+pub mod fmt;
+#[cfg(feature = "from-str")]
+mod from_str;
+pub mod packed;
+#[cfg(feature = "serde-representations")]
+pub mod serde_repr;
+
+pub use by_value::{BorrowInner, ByKey, ByValue, ToKey};
+#[cfg(feature = "dyn")]
+pub use coerce::CoerceFrom;
+pub use cons::{Cons, Nil};
+pub use error::{DisplayWithVariant, SumError};
+#[cfg(feature = "from-str")]
+pub use from_str::{FromStrError, StrictFromStrError};
+
 macro_rules! impl_sum {
 	(@into_inner $name:ident : $($t:ident)* : !) => (
 		impl $name {
@@ -48,6 +80,7 @@ macro_rules! impl_sum {
 	);
 	(@into_inner $name:ident : $($t:ident)* : $first_a:ident $($a:ident)*) => (
 		impl<$first_a> $name<$first_a, $($a,)*> {
+			/// Returns the inner value, whichever variant holds it, as every variant is of the same type. For `Sum1` this is the conversion back from `Sum1<A>` to `A`.
 			pub fn into_inner(self) -> $first_a {
 				match self {
 					$($name::$t(inner) => inner,)*
@@ -55,7 +88,291 @@ macro_rules! impl_sum {
 			}
 		}
 	);
-	($name:ident : $($t:ident $is:ident $map:ident $get:ident)* : $first_a:tt $($a:ident)* ) => (
+	(@unary_op $name:ident : $($t:ident)* : $trait:ident $method:ident) => (
+		#[cfg(feature = "ops")]
+		impl<$($t,)*> ops::$trait for $name<$($t,)*>
+		where
+			$($t: ops::$trait,)*
+		{
+			type Output = $name<$($t::Output,)*>;
+
+			fn $method(self) -> Self::Output {
+				match self {
+					$($name::$t(inner) => $name::$t(inner.$method()),)*
+				}
+			}
+		}
+	);
+	(@assign_op $name:ident : $($t:ident)* : $trait:ident $method:ident) => (
+		#[cfg(feature = "ops")]
+		impl<$($t,)* Rhs> ops::$trait<Rhs> for $name<$($t,)*>
+		where
+			$($t: ops::$trait<Rhs>,)*
+		{
+			fn $method(&mut self, rhs: Rhs) {
+				match *self {
+					$($name::$t(ref mut inner) => inner.$method(rhs),)*
+				}
+			}
+		}
+	);
+	(@binary_op $name:ident : $first_t:ident $($t:ident)* : $trait:ident $method:ident) => (
+		#[cfg(feature = "ops")]
+		impl<$first_t, $($t,)* Rhs> ops::$trait<Rhs> for $name<$first_t, $($t,)*>
+		where
+			$first_t: ops::$trait<Rhs>,
+			$($t: ops::$trait<Rhs, Output = $first_t::Output>,)*
+		{
+			type Output = $first_t::Output;
+
+			fn $method(self, rhs: Rhs) -> Self::Output {
+				match self {
+					$name::$first_t(inner) => inner.$method(rhs),
+					$($name::$t(inner) => inner.$method(rhs),)*
+				}
+			}
+		}
+	);
+	(@repr_c $name:ident : $($t:ident)*) => (
+		#[repr(C, u8)]
+		#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+		pub enum $name<$($t,)*> {
+			$($t($t),)*
+		}
+		impl<$($t,)*> From<$crate::$name<$($t,)*>> for $name<$($t,)*> {
+			fn from(sum: $crate::$name<$($t,)*>) -> Self {
+				match sum {
+					$($crate::$name::$t(inner) => $name::$t(inner),)*
+				}
+			}
+		}
+		impl<$($t,)*> From<$name<$($t,)*>> for $crate::$name<$($t,)*> {
+			fn from(sum: $name<$($t,)*>) -> Self {
+				match sum {
+					$($name::$t(inner) => $crate::$name::$t(inner),)*
+				}
+			}
+		}
+	);
+	(@vec $vec:ident $name:ident : $($t:ident $get:ident $index:literal)*) => (
+		/// A collection of `SumN` values that stores each variant in its own `Vec`, plus a tag per element recording the order.
+		#[derive(Clone, PartialEq, Eq, Hash)]
+		pub struct $vec<$($t,)*> {
+			tags: Vec<u8>,
+			$($get: Vec<$t>,)*
+		}
+		impl<$($t,)*> $vec<$($t,)*> {
+			pub fn new() -> Self {
+				$vec {
+					tags: Vec::new(),
+					$($get: Vec::new(),)*
+				}
+			}
+			pub fn len(&self) -> usize {
+				self.tags.len()
+			}
+			pub fn is_empty(&self) -> bool {
+				self.tags.is_empty()
+			}
+			pub fn push(&mut self, sum: $crate::$name<$($t,)*>) {
+				match sum {
+					$($crate::$name::$t(inner) => {
+						self.tags.push($index);
+						self.$get.push(inner);
+					})*
+				}
+			}
+			pub fn pop(&mut self) -> Option<$crate::$name<$($t,)*>> {
+				let tag = self.tags.pop()?;
+				match tag {
+					$($index => self.$get.pop().map($crate::$name::$t),)*
+					_ => unreachable!(),
+				}
+			}
+			pub fn clear(&mut self) {
+				self.tags.clear();
+				$(self.$get.clear();)*
+			}
+			/// Iterates over the elements in the order they were pushed.
+			pub fn iter(&self) -> impl ExactSizeIterator<Item = $crate::$name<$(&$t,)*>> + '_ {
+				$(let mut $get = self.$get.iter();)*
+				self.tags.iter().map(move |tag| match *tag {
+					$($index => $crate::$name::$t($get.next().unwrap()),)*
+					_ => unreachable!(),
+				})
+			}
+			pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = $crate::$name<$(&mut $t,)*>> + '_ {
+				$(let mut $get = self.$get.iter_mut();)*
+				self.tags.iter().map(move |tag| match *tag {
+					$($index => $crate::$name::$t($get.next().unwrap()),)*
+					_ => unreachable!(),
+				})
+			}
+			/// The elements of each variant, in the order they were pushed.
+			pub fn as_slices(&self) -> ($(&[$t],)*) {
+				($(&self.$get,)*)
+			}
+			pub fn as_mut_slices(&mut self) -> ($(&mut [$t],)*) {
+				($(&mut self.$get,)*)
+			}
+			/// Keeps only the elements for which `f` returns `true`, preserving their order.
+			pub fn retain<Func>(&mut self, mut f: Func)
+			where
+				Func: FnMut($crate::$name<$(&$t,)*>) -> bool,
+			{
+				// Removes the rejected elements when dropped, including if `f` panics, in which case those not yet visited are kept, as with `Vec::retain`.
+				struct Guard<'a, $($t,)*> {
+					vec: &'a mut $vec<$($t,)*>,
+					read: usize,
+					write: usize,
+					// Read and write positions within each variant's `Vec`.
+					$($get: (usize, usize),)*
+				}
+				impl<$($t,)*> Drop for Guard<'_, $($t,)*> {
+					fn drop(&mut self) {
+						drop(self.vec.tags.drain(self.write..self.read));
+						$(drop(self.vec.$get.drain(self.$get.1..self.$get.0));)*
+					}
+				}
+				let mut guard = Guard { vec: self, read: 0, write: 0, $($get: (0, 0),)* };
+				while guard.read < guard.vec.tags.len() {
+					let tag = guard.vec.tags[guard.read];
+					let keep = match tag {
+						$($index => {
+							let keep = f($crate::$name::$t(&guard.vec.$get[guard.$get.0]));
+							if keep {
+								guard.vec.$get.swap(guard.$get.1, guard.$get.0);
+								guard.$get.1 += 1;
+							}
+							guard.$get.0 += 1;
+							keep
+						})*
+						_ => unreachable!(),
+					};
+					if keep {
+						guard.vec.tags[guard.write] = tag;
+						guard.write += 1;
+					}
+					guard.read += 1;
+				}
+			}
+		}
+		impl<$($t,)*> Default for $vec<$($t,)*> {
+			fn default() -> Self {
+				$vec::new()
+			}
+		}
+		impl<$($t,)*> Debug for $vec<$($t,)*>
+		where
+			$($t: Debug,)*
+		{
+			fn fmt(&self, f: &mut Formatter) -> Result {
+				f.debug_list().entries(self.iter()).finish()
+			}
+		}
+		impl<$($t,)*> Extend<$crate::$name<$($t,)*>> for $vec<$($t,)*> {
+			fn extend<Iter>(&mut self, iter: Iter)
+			where
+				Iter: IntoIterator<Item = $crate::$name<$($t,)*>>,
+			{
+				for sum in iter {
+					self.push(sum);
+				}
+			}
+		}
+		impl<$($t,)*> FromIterator<$crate::$name<$($t,)*>> for $vec<$($t,)*> {
+			fn from_iter<Iter>(iter: Iter) -> Self
+			where
+				Iter: IntoIterator<Item = $crate::$name<$($t,)*>>,
+			{
+				let mut vec = $vec::new();
+				vec.extend(iter);
+				vec
+			}
+		}
+	);
+	(@fmt $name:ident : $($t:ident)* : $trait:ident) => (
+		#[cfg(feature = "fmt")]
+		impl<$($t,)*> ::std::fmt::$trait for $name<$($t,)*>
+		where
+			$($t: ::std::fmt::$trait,)*
+		{
+			fn fmt(&self, f: &mut Formatter) -> Result {
+				match *self {
+					$($name::$t(ref inner) => ::std::fmt::$trait::fmt(inner, f),)*
+				}
+			}
+		}
+	);
+	(@cons) => ($crate::Nil);
+	(@cons $first_t:ident $($t:ident)*) => ($crate::Cons<$first_t, impl_sum!(@cons $($t)*)>);
+	(@to_cons $name:ident $sum:ident [$($arms:tt)*] [$($wrap:tt)*] :) => (
+		match $sum {
+			$($arms)*
+		}
+	);
+	(@to_cons $name:ident $sum:ident [$($arms:tt)*] [$($wrap:tt)*] : $first_t:ident $($t:ident)*) => (
+		impl_sum!(@to_cons $name $sum [$($arms)* $name::$first_t(inner) => impl_sum!(@wrap [$($wrap)*] inner),] [$($wrap)* Tail] : $($t)*)
+	);
+	(@wrap [] $inner:expr) => ($crate::Cons::Head($inner));
+	(@wrap [$first_wrap:tt $($wrap:tt)*] $inner:expr) => ($crate::Cons::Tail(impl_sum!(@wrap [$($wrap)*] $inner)));
+	(@from_cons $name:ident $cons:ident :) => (
+		match $cons {}
+	);
+	(@from_cons $name:ident $cons:ident : $first_t:ident $($t:ident)*) => (
+		match $cons {
+			$crate::Cons::Head(inner) => $name::$first_t(inner),
+			$crate::Cons::Tail($cons) => impl_sum!(@from_cons $name $cons : $($t)*),
+		}
+	);
+	(@count) => (0);
+	(@count $first_t:ident $($t:ident)*) => (1 + impl_sum!(@count $($t)*));
+	(@variant_index $name:ident $sum:ident [$($arms:tt)*] [$($done:tt)*] :) => (
+		match *$sum {
+			$($arms)*
+		}
+	);
+	(@variant_index $name:ident $sum:ident [$($arms:tt)*] [$($done:tt)*] : $first_t:ident $($t:ident)*) => (
+		impl_sum!(@variant_index $name $sum [$($arms)* $name::$first_t(_) => impl_sum!(@count $($done)*),] [$($done)* $first_t] : $($t)*)
+	);
+	(@either $last_t:ident) => ($last_t);
+	(@either $first_t:ident $($t:ident)+) => (::either::Either<$first_t, impl_sum!(@either $($t)*)>);
+	(@to_either $name:ident $sum:ident [$($arms:tt)*] [$($wrap:tt)*] : $last_t:ident) => (
+		match $sum {
+			$($arms)*
+			$name::$last_t(inner) => impl_sum!(@either_wrap [$($wrap)*] inner),
+		}
+	);
+	(@to_either $name:ident $sum:ident [$($arms:tt)*] [$($wrap:tt)*] : $first_t:ident $($t:ident)+) => (
+		impl_sum!(@to_either $name $sum [$($arms)* $name::$first_t(inner) => impl_sum!(@either_wrap [$($wrap)*] ::either::Either::Left(inner)),] [$($wrap)* Right] : $($t)*)
+	);
+	(@either_wrap [] $inner:expr) => ($inner);
+	(@either_wrap [$first_wrap:tt $($wrap:tt)*] $inner:expr) => (::either::Either::Right(impl_sum!(@either_wrap [$($wrap)*] $inner)));
+	(@from_either $name:ident $either:ident : $last_t:ident) => (
+		$name::$last_t($either)
+	);
+	(@from_either $name:ident $either:ident : $first_t:ident $($t:ident)+) => (
+		match $either {
+			::either::Either::Left(inner) => $name::$first_t(inner),
+			::either::Either::Right($either) => impl_sum!(@from_either $name $either : $($t)*),
+		}
+	);
+	(@either_impls $name:ident : $($t:ident)?) => ();
+	(@either_impls $name:ident : $($t:ident)*) => (
+		#[cfg(feature = "either")]
+		impl<$($t,)*> From<$name<$($t,)*>> for impl_sum!(@either $($t)*) {
+			fn from(sum: $name<$($t,)*>) -> Self {
+				impl_sum!(@to_either $name sum [] [] : $($t)*)
+			}
+		}
+		#[cfg(feature = "either")]
+		impl<$($t,)*> From<impl_sum!(@either $($t)*)> for $name<$($t,)*> {
+			fn from(either: impl_sum!(@either $($t)*)) -> Self {
+				impl_sum!(@from_either $name either : $($t)*)
+			}
+		}
+	);
+	($name:ident : $($t:ident $is:ident $map:ident $get:ident $unwrap:ident $expect:ident $or:ident $or_else:ident $get_ref:ident $get_mut:ident $replace:ident $is_and:ident $default:ident $as_pin_ref:ident $as_pin_mut:ident $set:ident)* : $first_a:tt $($a:ident)* ) => (
 		#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 		#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 		pub enum $name<$($t,)*> {
@@ -82,31 +399,317 @@ macro_rules! impl_sum {
 					_ => None
 				}
 			}
+			pub fn $get_ref(&self) -> Option<&$t> {
+				match *self {
+					$name::$t(ref a) => Some(a),
+					_ => None
+				}
+			}
+			pub fn $get_mut(&mut self) -> Option<&mut $t> {
+				match *self {
+					$name::$t(ref mut a) => Some(a),
+					_ => None
+				}
+			}
+			#[track_caller]
+			pub fn $unwrap(self) -> $t {
+				match self {
+					$name::$t(a) => a,
+					other => panic!(concat!("called `", stringify!($name), "::", stringify!($unwrap), "()` on a `{}` value"), other.variant_name()),
+				}
+			}
+			#[track_caller]
+			pub fn $expect(self, msg: &str) -> $t {
+				match self {
+					$name::$t(a) => a,
+					other => panic!("{}: found a `{}` value", msg, other.variant_name()),
+				}
+			}
+			pub fn $or(self, default: $t) -> $t {
+				match self {
+					$name::$t(a) => a,
+					_ => default
+				}
+			}
+			pub fn $or_else<Func>(self, f: Func) -> $t where Func: FnOnce(Self) -> $t {
+				match self {
+					$name::$t(a) => a,
+					other => f(other)
+				}
+			}
+			#[allow(clippy::return_self_not_must_use)] // like `mem::replace`, the old value may be dropped
+			pub fn $replace(&mut self, value: $t) -> Self {
+				mem::replace(self, $name::$t(value))
+			}
+			pub fn $is_and<Func>(self, f: Func) -> bool where Func: FnOnce($t) -> bool {
+				match self {
+					$name::$t(a) => f(a),
+					_ => false
+				}
+			}
+			pub fn $default() -> Self where $t: Default {
+				$name::$t(Default::default())
+			}
+			pub fn $as_pin_ref(self: Pin<&Self>) -> Option<Pin<&$t>> {
+				match self.as_pin_ref() {
+					$name::$t(a) => Some(a),
+					_ => None
+				}
+			}
+			pub fn $as_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut $t>> {
+				match self.as_pin_mut() {
+					$name::$t(a) => Some(a),
+					_ => None
+				}
+			}
+			/// Replaces the pinned value with this variant holding `value`, dropping the previous value in place.
+			pub fn $set(mut self: Pin<&mut Self>, value: $t) {
+				self.set($name::$t(value));
+			}
 			)*
+			pub fn variant_name(&self) -> &'static str {
+				match *self {
+					$($name::$t(_) => stringify!($t),)*
+				}
+			}
+			pub fn variant_index(&self) -> usize {
+				impl_sum!(@variant_index $name self [] [] : $($t)*)
+			}
+			#[cfg(feature = "from-str")]
+			/// Parses `src` as each variant in turn, like `from_str`, but fails if more than one variant parses.
+			///
+			/// # Errors
+			///
+			/// Returns `Ambiguous` if more than one variant parses, and `NoMatch` with every variant's error if none do.
+			pub fn from_str_strict(src: &str) -> std::result::Result<Self, StrictFromStrError<($($t::Err,)*)>>
+			where
+				$($t: FromStr,)*
+			{
+				#[allow(unused_mut)] // for Sum0
+				let (mut sum, mut matched): (Option<Self>, _) = (None, Vec::new());
+				$(
+				let $get = match src.parse::<$t>() {
+					Ok(inner) => {
+						matched.push(stringify!($t));
+						if sum.is_none() {
+							sum = Some($name::$t(inner));
+						}
+						None
+					}
+					Err(err) => Some(err),
+				};
+				)*
+				if matched.len() > 1 {
+					return Err(StrictFromStrError::Ambiguous(matched));
+				}
+				match sum {
+					Some(sum) => Ok(sum),
+					// No variant parsed, so each holds its error.
+					None => Err(StrictFromStrError::NoMatch(FromStrError(($($get.unwrap(),)*)))),
+				}
+			}
+			#[allow(clippy::return_self_not_must_use)] // a `SumN` of references, not `Self`
 			pub fn as_ref(&self) -> $name<$(&$t,)*> {
 				match *self {
 					$($name::$t(ref a) => $name::$t(a),)*
 				}
 			}
+			#[allow(clippy::return_self_not_must_use)] // a `SumN` of references, not `Self`
 			pub fn as_mut(&mut self) -> $name<$(&mut $t,)*> {
 				match *self {
 					$($name::$t(ref mut a) => $name::$t(a),)*
 				}
 			}
-			pub fn as_pin_ref<'a>(self: Pin<&'a Self>) -> $name<$(Pin<&'a $t>,)*> {
-				unsafe {
-					match self.get_ref() {
-						$($name::$t(a) => $name::$t(Pin::new_unchecked(a)),)*
-						_ => unreachable_unchecked(),
-					}
+			#[allow(clippy::return_self_not_must_use)] // a `SumN` of references, not `Self`
+			pub fn as_deref(&self) -> $name<$(&$t::Target,)*>
+			where
+				$($t: Deref,)*
+			{
+				match *self {
+					$($name::$t(ref a) => $name::$t(&**a),)*
 				}
 			}
-			pub fn as_pin_mut<'a>(self: Pin<&'a mut Self>) -> $name<$(Pin<&'a mut $t>,)*> {
-				unsafe {
-					match self.get_unchecked_mut() {
-						$($name::$t(a) => $name::$t(Pin::new_unchecked(a)),)*
-						_ => unreachable_unchecked(),
-					}
+			#[allow(clippy::return_self_not_must_use)] // a `SumN` of references, not `Self`
+			pub fn as_deref_mut(&mut self) -> $name<$(&mut $t::Target,)*>
+			where
+				$($t: DerefMut,)*
+			{
+				match *self {
+					$($name::$t(ref mut a) => $name::$t(&mut **a),)*
+				}
+			}
+			// Variants are structurally pinned: no method moves a variant's value out of a `Pin<&mut Self>` unless `Self: Unpin`, there's no `Drop` impl, and `Unpin` is only implemented when every variant is.
+			pub fn as_pin_ref(self: Pin<&Self>) -> $name<$(Pin<&$t>,)*> {
+				match *self.get_ref() {
+					$($name::$t(ref a) => $name::$t(unsafe { Pin::new_unchecked(a) }),)*
+				}
+			}
+			pub fn as_pin_mut(self: Pin<&mut Self>) -> $name<$(Pin<&mut $t>,)*> {
+				match *unsafe { self.get_unchecked_mut() } {
+					$($name::$t(ref mut a) => $name::$t(unsafe { Pin::new_unchecked(a) }),)*
+				}
+			}
+			/// Replaces the pinned value, dropping the previous one in place, and returns which variant it was.
+			///
+			/// As every variant is structurally pinned, the previous value can't be moved out, so like `pin-project`'s `project_replace` for pinned fields, only a `PhantomData` of it is returned. When `Self: Unpin`, `replace` returns the value itself.
+			#[allow(unreachable_code, unused_mut)] // for `Sum0`, which has no variant to replace
+			pub fn project_replace(mut self: Pin<&mut Self>, replacement: Self) -> $name<$(PhantomData<$t>,)*> {
+				let replaced = match *self {
+					$($name::$t(_) => $name::$t(PhantomData),)*
+				};
+				self.set(replacement);
+				replaced
+			}
+			/// Replaces the pinned value, returning the previous one, which can be moved out as `Self: Unpin`.
+			#[must_use = "if unused, `set` may be used instead"]
+			pub fn replace(self: Pin<&mut Self>, replacement: Self) -> Self
+			where
+				Self: Unpin,
+			{
+				mem::replace(Pin::into_inner(self), replacement)
+			}
+			/// Converts whichever variant is active into `Owned`, such as `Sum2<&str, String>` into a `String`.
+			pub fn into_owned<Owned>(self) -> Owned
+			where
+				$($t: Into<Owned>,)*
+			{
+				match self {
+					$($name::$t(inner) => inner.into(),)*
+				}
+			}
+			/// Displays the active variant prefixed with its name, like `B: connection refused`.
+			pub fn display_with_variant(&self) -> DisplayWithVariant<'_, Self> {
+				DisplayWithVariant {
+					variant: self.variant_name(),
+					sum: self,
+				}
+			}
+		}
+		#[cfg(feature = "call")]
+		impl<$($t,)*> $name<$($t,)*> {
+			/// Calls the active variant, which every variant implements as `Fn(Args) -> Out`.
+			pub fn call<Args, Out>(&self, args: Args) -> Out
+			where
+				$($t: Fn(Args) -> Out,)*
+			{
+				match *self {
+					$($name::$t(ref inner) => inner(args),)*
+				}
+			}
+			pub fn call_mut<Args, Out>(&mut self, args: Args) -> Out
+			where
+				$($t: FnMut(Args) -> Out,)*
+			{
+				match *self {
+					$($name::$t(ref mut inner) => inner(args),)*
+				}
+			}
+			pub fn call_once<Args, Out>(self, args: Args) -> Out
+			where
+				$($t: FnOnce(Args) -> Out,)*
+			{
+				match self {
+					$($name::$t(inner) => inner(args),)*
+				}
+			}
+			/// Converts into a closure that calls the active variant, for APIs that take an `Fn`.
+			pub fn into_fn<Args, Out>(self) -> impl Fn(Args) -> Out
+			where
+				$($t: Fn(Args) -> Out,)*
+			{
+				move |args| self.call(args)
+			}
+		}
+		#[cfg(feature = "any")]
+		impl<$($t,)*> $name<$($t,)*> {
+			/// Downcasts `boxed` to each variant's type in turn, returning it unchanged if none match.
+			///
+			/// # Errors
+			///
+			/// Returns `boxed` if it isn't any of the variants' types.
+			pub fn from_any(boxed: Box<dyn Any>) -> std::result::Result<Self, Box<dyn Any>>
+			where
+				$($t: Any,)*
+			{
+				$(
+				let boxed = match boxed.downcast::<$t>() {
+					Ok(inner) => return Ok($name::$t(*inner)),
+					Err(boxed) => boxed,
+				};
+				)*
+				Err(boxed)
+			}
+			/// Like `from_any`, for `Send` payloads.
+			///
+			/// # Errors
+			///
+			/// Returns `boxed` if it isn't any of the variants' types.
+			pub fn from_any_send(boxed: Box<dyn Any + Send>) -> std::result::Result<Self, Box<dyn Any + Send>>
+			where
+				$($t: Any,)*
+			{
+				$(
+				let boxed = match boxed.downcast::<$t>() {
+					Ok(inner) => return Ok($name::$t(*inner)),
+					Err(boxed) => boxed,
+				};
+				)*
+				Err(boxed)
+			}
+			pub fn as_any(&self) -> &dyn Any
+			where
+				$($t: Any,)*
+			{
+				match *self {
+					$($name::$t(ref inner) => inner,)*
+				}
+			}
+			pub fn as_any_mut(&mut self) -> &mut dyn Any
+			where
+				$($t: Any,)*
+			{
+				match *self {
+					$($name::$t(ref mut inner) => inner,)*
+				}
+			}
+			/// The `TypeId` of the active variant's type.
+			pub fn type_id_of_active(&self) -> TypeId
+			where
+				$($t: Any,)*
+			{
+				match *self {
+					$($name::$t(_) => TypeId::of::<$t>(),)*
+				}
+			}
+		}
+		#[cfg(feature = "dyn")]
+		impl<$($t,)*> $name<$($t,)*> {
+			/// Views the active variant as the trait object `Dyn`, such as `dyn Debug`.
+			pub fn as_dyn<Dyn>(&self) -> &Dyn
+			where
+				Dyn: ?Sized,
+				$(Dyn: CoerceFrom<$t>,)*
+			{
+				match *self {
+					$($name::$t(ref inner) => <Dyn as CoerceFrom<$t>>::coerce_ref(inner),)*
+				}
+			}
+			pub fn as_dyn_mut<Dyn>(&mut self) -> &mut Dyn
+			where
+				Dyn: ?Sized,
+				$(Dyn: CoerceFrom<$t>,)*
+			{
+				match *self {
+					$($name::$t(ref mut inner) => <Dyn as CoerceFrom<$t>>::coerce_mut(inner),)*
+				}
+			}
+			pub fn into_dyn<Dyn>(self) -> Box<Dyn>
+			where
+				Dyn: ?Sized,
+				$(Dyn: CoerceFrom<$t>,)*
+			{
+				match self {
+					$($name::$t(inner) => <Dyn as CoerceFrom<$t>>::coerce_box(Box::new(inner)),)*
 				}
 			}
 		}
@@ -141,32 +744,271 @@ macro_rules! impl_sum {
 					$($name::$t(ref inner) => inner.description(),)*
 				}
 			}
-			#[allow(deprecated)]
-			fn cause(&self) -> Option<&dyn Error> {
-				match *self {
-					$($name::$t(ref inner) => inner.cause(),)*
+			#[allow(deprecated)]
+			fn cause(&self) -> Option<&dyn Error> {
+				match *self {
+					$($name::$t(ref inner) => inner.cause(),)*
+				}
+			}
+			fn source(&self) -> Option<&(dyn Error + 'static)> {
+				match *self {
+					$($name::$t(ref inner) => inner.source(),)*
+				}
+			}
+		}
+		impl<$($t,)*> Display for $name<$($t,)*>
+		where
+			$($t: Display,)*
+		{
+			fn fmt(&self, f: &mut Formatter) -> Result {
+				match *self {
+					$($name::$t(ref inner) => inner.fmt(f),)*
+				}
+			}
+		}
+		impl_sum!(@unary_op $name : $($t)* : Neg neg);
+		impl_sum!(@unary_op $name : $($t)* : Not not);
+		impl_sum!(@assign_op $name : $($t)* : AddAssign add_assign);
+		impl_sum!(@assign_op $name : $($t)* : SubAssign sub_assign);
+		impl_sum!(@assign_op $name : $($t)* : MulAssign mul_assign);
+		impl_sum!(@assign_op $name : $($t)* : DivAssign div_assign);
+		impl_sum!(@assign_op $name : $($t)* : RemAssign rem_assign);
+		impl_sum!(@assign_op $name : $($t)* : BitAndAssign bitand_assign);
+		impl_sum!(@assign_op $name : $($t)* : BitOrAssign bitor_assign);
+		impl_sum!(@assign_op $name : $($t)* : BitXorAssign bitxor_assign);
+		impl_sum!(@assign_op $name : $($t)* : ShlAssign shl_assign);
+		impl_sum!(@assign_op $name : $($t)* : ShrAssign shr_assign);
+		impl<$($t,)* Key> BorrowInner<Key> for $name<$($t,)*>
+		where
+			Key: ?Sized,
+			$($t: Borrow<Key>,)*
+		{
+			fn borrow_inner(&self) -> &Key {
+				match *self {
+					$($name::$t(ref inner) => inner.borrow(),)*
+				}
+			}
+		}
+		impl<$($t,)* Key> ToKey<Key> for $name<$($t,)*>
+		where
+			$($t: Clone + Into<Key>,)*
+		{
+			fn to_key(&self) -> Key {
+				match *self {
+					$($name::$t(ref inner) => inner.clone().into(),)*
+				}
+			}
+		}
+		impl_sum!(@fmt $name : $($t)* : LowerHex);
+		impl_sum!(@fmt $name : $($t)* : UpperHex);
+		impl_sum!(@fmt $name : $($t)* : Octal);
+		impl_sum!(@fmt $name : $($t)* : Binary);
+		impl_sum!(@fmt $name : $($t)* : LowerExp);
+		impl_sum!(@fmt $name : $($t)* : UpperExp);
+		impl_sum!(@fmt $name : $($t)* : Pointer);
+		impl<$($t,)*> Debug for fmt::Transparent<$name<$($t,)*>>
+		where
+			$($t: Debug,)*
+		{
+			fn fmt(&self, f: &mut Formatter) -> Result {
+				match self.0 {
+					$($name::$t(ref inner) => inner.fmt(f),)*
+				}
+			}
+		}
+		impl<$($t,)*> Debug for fmt::Transparent<&$name<$($t,)*>>
+		where
+			$($t: Debug,)*
+		{
+			fn fmt(&self, f: &mut Formatter) -> Result {
+				match *self.0 {
+					$($name::$t(ref inner) => inner.fmt(f),)*
+				}
+			}
+		}
+		/// Parses `src` as each variant in turn, returning the first that succeeds.
+		#[cfg(feature = "from-str")]
+		impl<$($t,)*> FromStr for $name<$($t,)*>
+		where
+			$($t: FromStr,)*
+		{
+			type Err = FromStrError<($($t::Err,)*)>;
+
+			fn from_str(src: &str) -> std::result::Result<Self, Self::Err> {
+				$(
+				let $get = match src.parse() {
+					Ok(inner) => return Ok($name::$t(inner)),
+					Err(err) => err,
+				};
+				)*
+				Err(FromStrError(($($get,)*)))
+			}
+		}
+		#[cfg(feature = "from-str")]
+		impl<$($t,)*> Display for FromStrError<($($t,)*)>
+		where
+			$($t: Display,)*
+		{
+			fn fmt(&self, formatter: &mut Formatter) -> Result {
+				let ($(ref $get,)*) = self.0;
+				let errors: &[(&str, &dyn Display)] = &[$((stringify!($t), $get),)*];
+				formatter.write_str("string did not match any variant")?;
+				for (index, (variant, error)) in errors.iter().enumerate() {
+					let separator = if index == 0 { ':' } else { ';' };
+					write!(formatter, "{} {}: {}", separator, variant, error)?;
+				}
+				Ok(())
+			}
+		}
+		#[cfg(feature = "from-str")]
+		impl<$($t,)*> Debug for FromStrError<($($t,)*)>
+		where
+			$($t: Debug,)*
+		{
+			fn fmt(&self, formatter: &mut Formatter) -> Result {
+				let ($(ref $get,)*) = self.0;
+				formatter.debug_tuple("FromStrError")$(.field($get))*.finish()
+			}
+		}
+		#[cfg(feature = "from-str")]
+		impl<$($t,)*> Error for FromStrError<($($t,)*)> where $($t: Error,)* {}
+		impl<$($t,)*> Display for SumError<$name<$($t,)*>> {
+			fn fmt(&self, f: &mut Formatter) -> Result {
+				if let Some(context) = self.context() {
+					write!(f, "{}, in ", context)?;
+				} else {
+					f.write_str("error in ")?;
+				}
+				write!(f, "variant {} of {}", self.get_ref().variant_name(), stringify!($name))
+			}
+		}
+		impl<$($t,)*> Error for SumError<$name<$($t,)*>>
+		where
+			$($t: Error + 'static,)*
+		{
+			fn source(&self) -> Option<&(dyn Error + 'static)> {
+				match *self.get_ref() {
+					$($name::$t(ref inner) => Some(inner),)*
+				}
+			}
+		}
+		impl<$($t,)*> From<$name<$($t,)*>> for impl_sum!(@cons $($t)*) {
+			fn from(sum: $name<$($t,)*>) -> Self {
+				impl_sum!(@to_cons $name sum [] [] : $($t)*)
+			}
+		}
+		impl<$($t,)*> From<impl_sum!(@cons $($t)*)> for $name<$($t,)*> {
+			fn from(cons: impl_sum!(@cons $($t)*)) -> Self {
+				impl_sum!(@from_cons $name cons : $($t)*)
+			}
+		}
+		#[cfg(feature = "serde-representations")]
+		impl<$($t,)*> $crate::serde_repr::SerializeVariants for $name<$($t,)*>
+		where
+			$($t: Serialize,)*
+		{
+			fn variant_name(&self) -> &'static str {
+				$name::variant_name(self)
+			}
+			fn variant_index(&self) -> usize {
+				$name::variant_index(self)
+			}
+			fn serialize_variant<Ser>(&self, serializer: Ser) -> std::result::Result<Ser::Ok, Ser::Error>
+			where
+				Ser: ::serde::Serializer,
+			{
+				match *self {
+					$($name::$t(ref inner) => inner.serialize(serializer),)*
+				}
+			}
+		}
+		#[cfg(feature = "serde-representations")]
+		impl<'de, $($t,)*> $crate::serde_repr::DeserializeVariants<'de> for $name<$($t,)*>
+		where
+			$($t: Deserialize<'de>,)*
+		{
+			const VARIANTS: &'static [&'static str] = &[$(stringify!($t),)*];
+
+			fn deserialize_variant<De>(index: usize, deserializer: De) -> std::result::Result<Self, De::Error>
+			where
+				De: ::serde::Deserializer<'de>,
+			{
+				match Self::VARIANTS.get(index) {
+					$(Some(&stringify!($t)) => $t::deserialize(deserializer).map($name::$t),)*
+					_ => Err(::serde::de::Error::invalid_value(::serde::de::Unexpected::Unsigned(index as u64), &"a variant index")),
+				}
+			}
+		}
+		impl_sum!(@either_impls $name : $($t)*);
+		impl_sum!(@multi $name : $($t)* : $first_a $($a)*);
+	);
+	(@multi $name:ident : : $first_a:tt $($a:ident)* ) => ();
+	(@multi $name:ident : $first_t:ident $($t:ident)* : $first_a:tt $($a:ident)* ) => (
+		impl<'a, $first_t, $($t,)*> $name<&'a $first_t, $(&'a $t,)*> {
+			pub fn cloned(self) -> $name<$first_t, $($t,)*>
+			where
+				$first_t: Clone,
+				$($t: Clone,)*
+			{
+				match self {
+					$name::$first_t(inner) => $name::$first_t(inner.clone()),
+					$($name::$t(inner) => $name::$t(inner.clone()),)*
+				}
+			}
+			pub fn copied(self) -> $name<$first_t, $($t,)*>
+			where
+				$first_t: Copy,
+				$($t: Copy,)*
+			{
+				match self {
+					$name::$first_t(inner) => $name::$first_t(*inner),
+					$($name::$t(inner) => $name::$t(*inner),)*
+				}
+			}
+		}
+		impl<'a, $first_t, $($t,)*> $name<&'a mut $first_t, $(&'a mut $t,)*> {
+			pub fn cloned(self) -> $name<$first_t, $($t,)*>
+			where
+				$first_t: Clone,
+				$($t: Clone,)*
+			{
+				match self {
+					$name::$first_t(inner) => $name::$first_t(inner.clone()),
+					$($name::$t(inner) => $name::$t(inner.clone()),)*
 				}
 			}
-			fn source(&self) -> Option<&(dyn Error + 'static)> {
-				match *self {
-					$($name::$t(ref inner) => inner.source(),)*
+			pub fn copied(self) -> $name<$first_t, $($t,)*>
+			where
+				$first_t: Copy,
+				$($t: Copy,)*
+			{
+				match self {
+					$name::$first_t(inner) => $name::$first_t(*inner),
+					$($name::$t(inner) => $name::$t(*inner),)*
 				}
 			}
 		}
-		impl<$($t,)*> Display for $name<$($t,)*>
+
+		impl<$first_t, $($t,)*> Default for $name<$first_t, $($t,)*>
 		where
-			$($t: Display,)*
+			$first_t: Default,
 		{
-			fn fmt(&self, f: &mut Formatter) -> Result {
-				match *self {
-					$($name::$t(ref inner) => inner.fmt(f),)*
-				}
+			fn default() -> Self {
+				$name::$first_t(Default::default())
 			}
 		}
-		impl_sum!(@multi $name : $($t $is $map $get)* : $first_a $($a)*);
-	);
-	(@multi $name:ident : : $first_a:tt $($a:ident)* ) => ();
-	(@multi $name:ident : $first_t:ident $first_is:ident $first_map:ident $first_get:ident $($t:ident $is:ident $map:ident $get:ident)* : $first_a:tt $($a:ident)* ) => (
+
+		impl_sum!(@binary_op $name : $first_t $($t)* : Add add);
+		impl_sum!(@binary_op $name : $first_t $($t)* : Sub sub);
+		impl_sum!(@binary_op $name : $first_t $($t)* : Mul mul);
+		impl_sum!(@binary_op $name : $first_t $($t)* : Div div);
+		impl_sum!(@binary_op $name : $first_t $($t)* : Rem rem);
+		impl_sum!(@binary_op $name : $first_t $($t)* : BitAnd bitand);
+		impl_sum!(@binary_op $name : $first_t $($t)* : BitOr bitor);
+		impl_sum!(@binary_op $name : $first_t $($t)* : BitXor bitxor);
+		impl_sum!(@binary_op $name : $first_t $($t)* : Shl shl);
+		impl_sum!(@binary_op $name : $first_t $($t)* : Shr shr);
+
 		impl<$first_t, $($t,)*> Deref for $name<$first_t, $($t,)*>
 		where
 			$first_t: Deref,
@@ -252,6 +1094,61 @@ macro_rules! impl_sum {
 				}
 			}
 		}
+		#[cfg(feature = "rayon")]
+		impl<$first_t, $($t,)*> ::rayon::iter::ParallelIterator for $name<$first_t, $($t,)*>
+		where
+			$first_t: ::rayon::iter::ParallelIterator,
+			$($t: ::rayon::iter::ParallelIterator<Item = $first_t::Item>,)*
+		{
+			type Item = <$first_t>::Item;
+
+			fn drive_unindexed<Consumer>(self, consumer: Consumer) -> Consumer::Result
+			where
+				Consumer: ::rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+			{
+				match self {
+					$name::$first_t(inner) => inner.drive_unindexed(consumer),
+					$($name::$t(inner) => inner.drive_unindexed(consumer),)*
+				}
+			}
+			fn opt_len(&self) -> Option<usize> {
+				match *self {
+					$name::$first_t(ref inner) => inner.opt_len(),
+					$($name::$t(ref inner) => inner.opt_len(),)*
+				}
+			}
+		}
+		#[cfg(feature = "rayon")]
+		impl<$first_t, $($t,)*> ::rayon::iter::IndexedParallelIterator for $name<$first_t, $($t,)*>
+		where
+			$first_t: ::rayon::iter::IndexedParallelIterator,
+			$($t: ::rayon::iter::IndexedParallelIterator<Item = $first_t::Item>,)*
+		{
+			fn drive<Consumer>(self, consumer: Consumer) -> Consumer::Result
+			where
+				Consumer: ::rayon::iter::plumbing::Consumer<Self::Item>,
+			{
+				match self {
+					$name::$first_t(inner) => inner.drive(consumer),
+					$($name::$t(inner) => inner.drive(consumer),)*
+				}
+			}
+			fn len(&self) -> usize {
+				match *self {
+					$name::$first_t(ref inner) => inner.len(),
+					$($name::$t(ref inner) => inner.len(),)*
+				}
+			}
+			fn with_producer<Callback>(self, callback: Callback) -> Callback::Output
+			where
+				Callback: ::rayon::iter::plumbing::ProducerCallback<Self::Item>,
+			{
+				match self {
+					$name::$first_t(inner) => inner.with_producer(callback),
+					$($name::$t(inner) => inner.with_producer(callback),)*
+				}
+			}
+		}
 		#[cfg(feature = "futures")]
 		impl<$first_t, $($t,)*> Stream for $name<$first_t, $($t,)*>
 		where
@@ -274,69 +1171,307 @@ macro_rules! impl_sum {
 #[cfg(feature = "0")]
 impl_sum!(Sum0: : !);
 #[cfg(feature = "1")]
-impl_sum!(Sum1: A is_a map_a a: A);
+impl_sum!(Sum1: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a: A);
 #[cfg(feature = "2")]
-impl_sum!(Sum2: A is_a map_a a B is_b map_b b: A A);
+impl_sum!(Sum2: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b: A A);
 #[cfg(feature = "3")]
-impl_sum!(Sum3: A is_a map_a a B is_b map_b b C is_c map_c c: A A A);
+impl_sum!(Sum3: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c: A A A);
 #[cfg(feature = "4")]
-impl_sum!(Sum4: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d: A A A A);
+impl_sum!(Sum4: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d: A A A A);
 #[cfg(feature = "5")]
-impl_sum!(Sum5: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e: A A A A A);
+impl_sum!(Sum5: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e: A A A A A);
 #[cfg(feature = "6")]
-impl_sum!(Sum6: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f: A A A A A A);
+impl_sum!(Sum6: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f: A A A A A A);
 #[cfg(feature = "7")]
-impl_sum!(Sum7: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g: A A A A A A A);
+impl_sum!(Sum7: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g: A A A A A A A);
 #[cfg(feature = "8")]
-impl_sum!(Sum8: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h: A A A A A A A A);
+impl_sum!(Sum8: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h: A A A A A A A A);
 #[cfg(feature = "9")]
-impl_sum!(Sum9: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i: A A A A A A A A A);
+impl_sum!(Sum9: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i: A A A A A A A A A);
 #[cfg(feature = "10")]
-impl_sum!(Sum10: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j: A A A A A A A A A A);
+impl_sum!(Sum10: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j: A A A A A A A A A A);
 #[cfg(feature = "11")]
-impl_sum!(Sum11: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k: A A A A A A A A A A A);
+impl_sum!(Sum11: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k: A A A A A A A A A A A);
 #[cfg(feature = "12")]
-impl_sum!(Sum12: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l: A A A A A A A A A A A A);
+impl_sum!(Sum12: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l: A A A A A A A A A A A A);
 #[cfg(feature = "13")]
-impl_sum!(Sum13: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m: A A A A A A A A A A A A A);
+impl_sum!(Sum13: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m: A A A A A A A A A A A A A);
 #[cfg(feature = "14")]
-impl_sum!(Sum14: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n: A A A A A A A A A A A A A A);
+impl_sum!(Sum14: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n: A A A A A A A A A A A A A A);
 #[cfg(feature = "15")]
-impl_sum!(Sum15: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o: A A A A A A A A A A A A A A A);
+impl_sum!(Sum15: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o: A A A A A A A A A A A A A A A);
 #[cfg(feature = "16")]
-impl_sum!(Sum16: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p: A A A A A A A A A A A A A A A A);
+impl_sum!(Sum16: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p: A A A A A A A A A A A A A A A A);
 #[cfg(feature = "17")]
-impl_sum!(Sum17: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q: A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum17: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q: A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "18")]
-impl_sum!(Sum18: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r: A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum18: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r: A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "19")]
-impl_sum!(Sum19: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s: A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum19: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s: A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "20")]
-impl_sum!(Sum20: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t: A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum20: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t: A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "21")]
-impl_sum!(Sum21: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u: A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum21: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u: A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "22")]
-impl_sum!(Sum22: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v: A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum22: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v: A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "23")]
-impl_sum!(Sum23: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w: A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum23: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w: A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "24")]
-impl_sum!(Sum24: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x: A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum24: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x: A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "25")]
-impl_sum!(Sum25: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y: A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum25: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y: A A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "26")]
-impl_sum!(Sum26: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y Z is_z map_z z: A A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum26: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y Z is_z map_z z unwrap_z expect_z z_or z_or_else z_ref z_mut replace_z is_z_and default_z as_pin_ref_z as_pin_mut_z set_z: A A A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "27")]
-impl_sum!(Sum27: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y Z is_z map_z z Aa is_aa map_aa aa: A A A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum27: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y Z is_z map_z z unwrap_z expect_z z_or z_or_else z_ref z_mut replace_z is_z_and default_z as_pin_ref_z as_pin_mut_z set_z Aa is_aa map_aa aa unwrap_aa expect_aa aa_or aa_or_else aa_ref aa_mut replace_aa is_aa_and default_aa as_pin_ref_aa as_pin_mut_aa set_aa: A A A A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "28")]
-impl_sum!(Sum28: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y Z is_z map_z z Aa is_aa map_aa aa Ab is_ab map_ab ab: A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum28: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y Z is_z map_z z unwrap_z expect_z z_or z_or_else z_ref z_mut replace_z is_z_and default_z as_pin_ref_z as_pin_mut_z set_z Aa is_aa map_aa aa unwrap_aa expect_aa aa_or aa_or_else aa_ref aa_mut replace_aa is_aa_and default_aa as_pin_ref_aa as_pin_mut_aa set_aa Ab is_ab map_ab ab unwrap_ab expect_ab ab_or ab_or_else ab_ref ab_mut replace_ab is_ab_and default_ab as_pin_ref_ab as_pin_mut_ab set_ab: A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "29")]
-impl_sum!(Sum29: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y Z is_z map_z z Aa is_aa map_aa aa Ab is_ab map_ab ab Ac is_ac map_ac ac: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum29: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y Z is_z map_z z unwrap_z expect_z z_or z_or_else z_ref z_mut replace_z is_z_and default_z as_pin_ref_z as_pin_mut_z set_z Aa is_aa map_aa aa unwrap_aa expect_aa aa_or aa_or_else aa_ref aa_mut replace_aa is_aa_and default_aa as_pin_ref_aa as_pin_mut_aa set_aa Ab is_ab map_ab ab unwrap_ab expect_ab ab_or ab_or_else ab_ref ab_mut replace_ab is_ab_and default_ab as_pin_ref_ab as_pin_mut_ab set_ab Ac is_ac map_ac ac unwrap_ac expect_ac ac_or ac_or_else ac_ref ac_mut replace_ac is_ac_and default_ac as_pin_ref_ac as_pin_mut_ac set_ac: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "30")]
-impl_sum!(Sum30: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y Z is_z map_z z Aa is_aa map_aa aa Ab is_ab map_ab ab Ac is_ac map_ac ac Ad is_ad map_ad ad: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum30: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y Z is_z map_z z unwrap_z expect_z z_or z_or_else z_ref z_mut replace_z is_z_and default_z as_pin_ref_z as_pin_mut_z set_z Aa is_aa map_aa aa unwrap_aa expect_aa aa_or aa_or_else aa_ref aa_mut replace_aa is_aa_and default_aa as_pin_ref_aa as_pin_mut_aa set_aa Ab is_ab map_ab ab unwrap_ab expect_ab ab_or ab_or_else ab_ref ab_mut replace_ab is_ab_and default_ab as_pin_ref_ab as_pin_mut_ab set_ab Ac is_ac map_ac ac unwrap_ac expect_ac ac_or ac_or_else ac_ref ac_mut replace_ac is_ac_and default_ac as_pin_ref_ac as_pin_mut_ac set_ac Ad is_ad map_ad ad unwrap_ad expect_ad ad_or ad_or_else ad_ref ad_mut replace_ad is_ad_and default_ad as_pin_ref_ad as_pin_mut_ad set_ad: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "31")]
-impl_sum!(Sum31: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y Z is_z map_z z Aa is_aa map_aa aa Ab is_ab map_ab ab Ac is_ac map_ac ac Ad is_ad map_ad ad Ae is_ae map_ae ae: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum31: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y Z is_z map_z z unwrap_z expect_z z_or z_or_else z_ref z_mut replace_z is_z_and default_z as_pin_ref_z as_pin_mut_z set_z Aa is_aa map_aa aa unwrap_aa expect_aa aa_or aa_or_else aa_ref aa_mut replace_aa is_aa_and default_aa as_pin_ref_aa as_pin_mut_aa set_aa Ab is_ab map_ab ab unwrap_ab expect_ab ab_or ab_or_else ab_ref ab_mut replace_ab is_ab_and default_ab as_pin_ref_ab as_pin_mut_ab set_ab Ac is_ac map_ac ac unwrap_ac expect_ac ac_or ac_or_else ac_ref ac_mut replace_ac is_ac_and default_ac as_pin_ref_ac as_pin_mut_ac set_ac Ad is_ad map_ad ad unwrap_ad expect_ad ad_or ad_or_else ad_ref ad_mut replace_ad is_ad_and default_ad as_pin_ref_ad as_pin_mut_ad set_ad Ae is_ae map_ae ae unwrap_ae expect_ae ae_or ae_or_else ae_ref ae_mut replace_ae is_ae_and default_ae as_pin_ref_ae as_pin_mut_ae set_ae: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
 #[cfg(feature = "32")]
-impl_sum!(Sum32: A is_a map_a a B is_b map_b b C is_c map_c c D is_d map_d d E is_e map_e e F is_f map_f f G is_g map_g g H is_h map_h h I is_i map_i i J is_j map_j j K is_k map_k k L is_l map_l l M is_m map_m m N is_n map_n n O is_o map_o o P is_p map_p p Q is_q map_q q R is_r map_r r S is_s map_s s T is_t map_t t U is_u map_u u V is_v map_v v W is_w map_w w X is_x map_x x Y is_y map_y y Z is_z map_z z Aa is_aa map_aa aa Ab is_ab map_ab ab Ac is_ac map_ac ac Ad is_ad map_ad ad Ae is_ae map_ae ae Af is_af map_af af: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
+impl_sum!(Sum32: A is_a map_a a unwrap_a expect_a a_or a_or_else a_ref a_mut replace_a is_a_and default_a as_pin_ref_a as_pin_mut_a set_a B is_b map_b b unwrap_b expect_b b_or b_or_else b_ref b_mut replace_b is_b_and default_b as_pin_ref_b as_pin_mut_b set_b C is_c map_c c unwrap_c expect_c c_or c_or_else c_ref c_mut replace_c is_c_and default_c as_pin_ref_c as_pin_mut_c set_c D is_d map_d d unwrap_d expect_d d_or d_or_else d_ref d_mut replace_d is_d_and default_d as_pin_ref_d as_pin_mut_d set_d E is_e map_e e unwrap_e expect_e e_or e_or_else e_ref e_mut replace_e is_e_and default_e as_pin_ref_e as_pin_mut_e set_e F is_f map_f f unwrap_f expect_f f_or f_or_else f_ref f_mut replace_f is_f_and default_f as_pin_ref_f as_pin_mut_f set_f G is_g map_g g unwrap_g expect_g g_or g_or_else g_ref g_mut replace_g is_g_and default_g as_pin_ref_g as_pin_mut_g set_g H is_h map_h h unwrap_h expect_h h_or h_or_else h_ref h_mut replace_h is_h_and default_h as_pin_ref_h as_pin_mut_h set_h I is_i map_i i unwrap_i expect_i i_or i_or_else i_ref i_mut replace_i is_i_and default_i as_pin_ref_i as_pin_mut_i set_i J is_j map_j j unwrap_j expect_j j_or j_or_else j_ref j_mut replace_j is_j_and default_j as_pin_ref_j as_pin_mut_j set_j K is_k map_k k unwrap_k expect_k k_or k_or_else k_ref k_mut replace_k is_k_and default_k as_pin_ref_k as_pin_mut_k set_k L is_l map_l l unwrap_l expect_l l_or l_or_else l_ref l_mut replace_l is_l_and default_l as_pin_ref_l as_pin_mut_l set_l M is_m map_m m unwrap_m expect_m m_or m_or_else m_ref m_mut replace_m is_m_and default_m as_pin_ref_m as_pin_mut_m set_m N is_n map_n n unwrap_n expect_n n_or n_or_else n_ref n_mut replace_n is_n_and default_n as_pin_ref_n as_pin_mut_n set_n O is_o map_o o unwrap_o expect_o o_or o_or_else o_ref o_mut replace_o is_o_and default_o as_pin_ref_o as_pin_mut_o set_o P is_p map_p p unwrap_p expect_p p_or p_or_else p_ref p_mut replace_p is_p_and default_p as_pin_ref_p as_pin_mut_p set_p Q is_q map_q q unwrap_q expect_q q_or q_or_else q_ref q_mut replace_q is_q_and default_q as_pin_ref_q as_pin_mut_q set_q R is_r map_r r unwrap_r expect_r r_or r_or_else r_ref r_mut replace_r is_r_and default_r as_pin_ref_r as_pin_mut_r set_r S is_s map_s s unwrap_s expect_s s_or s_or_else s_ref s_mut replace_s is_s_and default_s as_pin_ref_s as_pin_mut_s set_s T is_t map_t t unwrap_t expect_t t_or t_or_else t_ref t_mut replace_t is_t_and default_t as_pin_ref_t as_pin_mut_t set_t U is_u map_u u unwrap_u expect_u u_or u_or_else u_ref u_mut replace_u is_u_and default_u as_pin_ref_u as_pin_mut_u set_u V is_v map_v v unwrap_v expect_v v_or v_or_else v_ref v_mut replace_v is_v_and default_v as_pin_ref_v as_pin_mut_v set_v W is_w map_w w unwrap_w expect_w w_or w_or_else w_ref w_mut replace_w is_w_and default_w as_pin_ref_w as_pin_mut_w set_w X is_x map_x x unwrap_x expect_x x_or x_or_else x_ref x_mut replace_x is_x_and default_x as_pin_ref_x as_pin_mut_x set_x Y is_y map_y y unwrap_y expect_y y_or y_or_else y_ref y_mut replace_y is_y_and default_y as_pin_ref_y as_pin_mut_y set_y Z is_z map_z z unwrap_z expect_z z_or z_or_else z_ref z_mut replace_z is_z_and default_z as_pin_ref_z as_pin_mut_z set_z Aa is_aa map_aa aa unwrap_aa expect_aa aa_or aa_or_else aa_ref aa_mut replace_aa is_aa_and default_aa as_pin_ref_aa as_pin_mut_aa set_aa Ab is_ab map_ab ab unwrap_ab expect_ab ab_or ab_or_else ab_ref ab_mut replace_ab is_ab_and default_ab as_pin_ref_ab as_pin_mut_ab set_ab Ac is_ac map_ac ac unwrap_ac expect_ac ac_or ac_or_else ac_ref ac_mut replace_ac is_ac_and default_ac as_pin_ref_ac as_pin_mut_ac set_ac Ad is_ad map_ad ad unwrap_ad expect_ad ad_or ad_or_else ad_ref ad_mut replace_ad is_ad_and default_ad as_pin_ref_ad as_pin_mut_ad set_ad Ae is_ae map_ae ae unwrap_ae expect_ae ae_or ae_or_else ae_ref ae_mut replace_ae is_ae_and default_ae as_pin_ref_ae as_pin_mut_ae set_ae Af is_af map_af af unwrap_af expect_af af_or af_or_else af_ref af_mut replace_af is_af_and default_af as_pin_ref_af as_pin_mut_af set_af: A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A A);
+
+// Declared after the invocations above, so they can use `impl_sum!` and refer to the types it generated.
+#[cfg(feature = "repr-c")]
+pub mod repr_c;
+#[cfg(feature = "sum-vec")]
+mod sum_vec;
+
+#[cfg(feature = "sum-vec")]
+pub use sum_vec::*;
+
+/// Converting back is [`Sum1::into_inner`], as the orphan rules don't permit `impl<A> From<Sum1<A>> for A`.
+#[cfg(feature = "1")]
+impl<A> From<A> for Sum1<A> {
+	fn from(a: A) -> Self {
+		Sum1::A(a)
+	}
+}
+#[cfg(feature = "2")]
+impl<A, B> From<std::result::Result<A, B>> for Sum2<A, B> {
+	fn from(result: std::result::Result<A, B>) -> Self {
+		match result {
+			Ok(a) => Sum2::A(a),
+			Err(b) => Sum2::B(b),
+		}
+	}
+}
+#[cfg(feature = "2")]
+impl<A, B> From<Sum2<A, B>> for std::result::Result<A, B> {
+	fn from(sum: Sum2<A, B>) -> Self {
+		match sum {
+			Sum2::A(a) => Ok(a),
+			Sum2::B(b) => Err(b),
+		}
+	}
+}
+#[cfg(feature = "2")]
+impl<A> From<Option<A>> for Sum2<A, ()> {
+	fn from(option: Option<A>) -> Self {
+		match option {
+			Some(a) => Sum2::A(a),
+			None => Sum2::B(()),
+		}
+	}
+}
+#[cfg(feature = "2")]
+impl<A> From<Sum2<A, ()>> for Option<A> {
+	fn from(sum: Sum2<A, ()>) -> Self {
+		match sum {
+			Sum2::A(a) => Some(a),
+			Sum2::B(()) => None,
+		}
+	}
+}
+#[cfg(feature = "2")]
+impl<'a, A: ?Sized + ToOwned> From<Cow<'a, A>> for Sum2<&'a A, A::Owned> {
+	fn from(cow: Cow<'a, A>) -> Self {
+		match cow {
+			Cow::Borrowed(a) => Sum2::A(a),
+			Cow::Owned(b) => Sum2::B(b),
+		}
+	}
+}
+#[cfg(feature = "2")]
+impl<'a, A: ?Sized + ToOwned> From<Sum2<&'a A, A::Owned>> for Cow<'a, A> {
+	fn from(sum: Sum2<&'a A, A::Owned>) -> Self {
+		match sum {
+			Sum2::A(a) => Cow::Borrowed(a),
+			Sum2::B(b) => Cow::Owned(b),
+		}
+	}
+}
+
+/// Expands to the `SumN` type with as many variants as types given.
+///
+/// `Sum![u8, String, bool]` is `Sum3<u8, String, bool>`, so adding or removing an alternative doesn't require renaming every `Sum3` to `Sum4`.
+#[macro_export]
+macro_rules! Sum {
+	() => ($crate::Sum0);
+	($a:ty $(,)?) => ($crate::Sum1<$a>);
+	($a:ty, $b:ty $(,)?) => ($crate::Sum2<$a, $b>);
+	($a:ty, $b:ty, $c:ty $(,)?) => ($crate::Sum3<$a, $b, $c>);
+	($a:ty, $b:ty, $c:ty, $d:ty $(,)?) => ($crate::Sum4<$a, $b, $c, $d>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty $(,)?) => ($crate::Sum5<$a, $b, $c, $d, $e>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty $(,)?) => ($crate::Sum6<$a, $b, $c, $d, $e, $f>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty $(,)?) => ($crate::Sum7<$a, $b, $c, $d, $e, $f, $g>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty $(,)?) => ($crate::Sum8<$a, $b, $c, $d, $e, $f, $g, $h>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty $(,)?) => ($crate::Sum9<$a, $b, $c, $d, $e, $f, $g, $h, $i>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty $(,)?) => ($crate::Sum10<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty $(,)?) => ($crate::Sum11<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty $(,)?) => ($crate::Sum12<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty $(,)?) => ($crate::Sum13<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty $(,)?) => ($crate::Sum14<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty $(,)?) => ($crate::Sum15<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty $(,)?) => ($crate::Sum16<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty $(,)?) => ($crate::Sum17<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty $(,)?) => ($crate::Sum18<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty $(,)?) => ($crate::Sum19<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty $(,)?) => ($crate::Sum20<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty $(,)?) => ($crate::Sum21<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty $(,)?) => ($crate::Sum22<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty $(,)?) => ($crate::Sum23<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty $(,)?) => ($crate::Sum24<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty $(,)?) => ($crate::Sum25<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty, $z:ty $(,)?) => ($crate::Sum26<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y, $z>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty, $z:ty, $aa:ty $(,)?) => ($crate::Sum27<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y, $z, $aa>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty, $z:ty, $aa:ty, $ab:ty $(,)?) => ($crate::Sum28<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y, $z, $aa, $ab>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty, $z:ty, $aa:ty, $ab:ty, $ac:ty $(,)?) => ($crate::Sum29<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y, $z, $aa, $ab, $ac>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty, $z:ty, $aa:ty, $ab:ty, $ac:ty, $ad:ty $(,)?) => ($crate::Sum30<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y, $z, $aa, $ab, $ac, $ad>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty, $z:ty, $aa:ty, $ab:ty, $ac:ty, $ad:ty, $ae:ty $(,)?) => ($crate::Sum31<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y, $z, $aa, $ab, $ac, $ad, $ae>);
+	($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty, $j:ty, $k:ty, $l:ty, $m:ty, $n:ty, $o:ty, $p:ty, $q:ty, $r:ty, $s:ty, $t:ty, $u:ty, $v:ty, $w:ty, $x:ty, $y:ty, $z:ty, $aa:ty, $ab:ty, $ac:ty, $ad:ty, $ae:ty, $af:ty $(,)?) => ($crate::Sum32<$a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p, $q, $r, $s, $t, $u, $v, $w, $x, $y, $z, $aa, $ab, $ac, $ad, $ae, $af>);
+	($($t:ty),* $(,)?) => (compile_error!("Sum! supports at most 32 types; use Cons! for more"));
+}
+
+/// Constructs a `SumN` value by zero-based variant index and arity.
+///
+/// `sum!(2 of 4: x)` is `Sum4::C(x)`, with the other type parameters inferred. The index must be less than the arity:
+///
+/// ```compile_fail
+/// let x: sum::Sum2<u8, u8> = sum::sum!(2 of 2: 1);
+/// ```
+#[macro_export]
+macro_rules! sum {
+	(0 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 0 [_] <$crate::__sum_n!($n)>::A($e)));
+	(1 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 1 [_ _] <$crate::__sum_n!($n)>::B($e)));
+	(2 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 2 [_ _ _] <$crate::__sum_n!($n)>::C($e)));
+	(3 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 3 [_ _ _ _] <$crate::__sum_n!($n)>::D($e)));
+	(4 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 4 [_ _ _ _ _] <$crate::__sum_n!($n)>::E($e)));
+	(5 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 5 [_ _ _ _ _ _] <$crate::__sum_n!($n)>::F($e)));
+	(6 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 6 [_ _ _ _ _ _ _] <$crate::__sum_n!($n)>::G($e)));
+	(7 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 7 [_ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::H($e)));
+	(8 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 8 [_ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::I($e)));
+	(9 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 9 [_ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::J($e)));
+	(10 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 10 [_ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::K($e)));
+	(11 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 11 [_ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::L($e)));
+	(12 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 12 [_ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::M($e)));
+	(13 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 13 [_ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::N($e)));
+	(14 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 14 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::O($e)));
+	(15 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 15 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::P($e)));
+	(16 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 16 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Q($e)));
+	(17 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 17 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::R($e)));
+	(18 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 18 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::S($e)));
+	(19 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 19 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::T($e)));
+	(20 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 20 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::U($e)));
+	(21 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 21 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::V($e)));
+	(22 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 22 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::W($e)));
+	(23 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 23 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::X($e)));
+	(24 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 24 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Y($e)));
+	(25 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 25 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Z($e)));
+	(26 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 26 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Aa($e)));
+	(27 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 27 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Ab($e)));
+	(28 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 28 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Ac($e)));
+	(29 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 29 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Ad($e)));
+	(30 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 30 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Ae($e)));
+	(31 of $n:tt : $e:expr) => ($crate::__sum_n!(@check $n 31 [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] <$crate::__sum_n!($n)>::Af($e)));
+	($i:tt of $n:tt : $e:expr) => (compile_error!("sum! supports at most 32 types"));
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sum_n {
+	(@check 0 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(0 $i [] [$($index)*] $($out)*));
+	(@check 1 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(1 $i [_] [$($index)*] $($out)*));
+	(@check 2 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(2 $i [_ _] [$($index)*] $($out)*));
+	(@check 3 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(3 $i [_ _ _] [$($index)*] $($out)*));
+	(@check 4 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(4 $i [_ _ _ _] [$($index)*] $($out)*));
+	(@check 5 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(5 $i [_ _ _ _ _] [$($index)*] $($out)*));
+	(@check 6 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(6 $i [_ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 7 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(7 $i [_ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 8 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(8 $i [_ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 9 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(9 $i [_ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 10 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(10 $i [_ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 11 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(11 $i [_ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 12 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(12 $i [_ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 13 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(13 $i [_ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 14 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(14 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 15 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(15 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 16 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(16 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 17 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(17 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 18 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(18 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 19 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(19 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 20 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(20 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 21 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(21 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 22 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(22 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 23 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(23 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 24 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(24 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 25 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(25 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 26 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(26 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 27 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(27 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 28 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(28 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 29 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(29 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 30 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(30 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 31 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(31 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check 32 $i:tt [$($index:tt)*] $($out:tt)*) => ($crate::__sum_check!(32 $i [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] [$($index)*] $($out)*));
+	(@check $n:tt $i:tt [$($index:tt)*] $($out:tt)*) => ($($out)*);
+	(0) => ($crate::Sum0);
+	(1) => ($crate::Sum1<_>);
+	(2) => ($crate::Sum2<_, _>);
+	(3) => ($crate::Sum3<_, _, _>);
+	(4) => ($crate::Sum4<_, _, _, _>);
+	(5) => ($crate::Sum5<_, _, _, _, _>);
+	(6) => ($crate::Sum6<_, _, _, _, _, _>);
+	(7) => ($crate::Sum7<_, _, _, _, _, _, _>);
+	(8) => ($crate::Sum8<_, _, _, _, _, _, _, _>);
+	(9) => ($crate::Sum9<_, _, _, _, _, _, _, _, _>);
+	(10) => ($crate::Sum10<_, _, _, _, _, _, _, _, _, _>);
+	(11) => ($crate::Sum11<_, _, _, _, _, _, _, _, _, _, _>);
+	(12) => ($crate::Sum12<_, _, _, _, _, _, _, _, _, _, _, _>);
+	(13) => ($crate::Sum13<_, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(14) => ($crate::Sum14<_, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(15) => ($crate::Sum15<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(16) => ($crate::Sum16<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(17) => ($crate::Sum17<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(18) => ($crate::Sum18<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(19) => ($crate::Sum19<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(20) => ($crate::Sum20<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(21) => ($crate::Sum21<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(22) => ($crate::Sum22<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(23) => ($crate::Sum23<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(24) => ($crate::Sum24<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(25) => ($crate::Sum25<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(26) => ($crate::Sum26<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(27) => ($crate::Sum27<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(28) => ($crate::Sum28<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(29) => ($crate::Sum29<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(30) => ($crate::Sum30<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(31) => ($crate::Sum31<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	(32) => ($crate::Sum32<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>);
+	($n:tt) => (compile_error!("sum! supports at most 32 types"));
+}
+
+/// Emits the tokens after the two unary counts if the index's count, one more than the index, is at most the arity's.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sum_check {
+	($n:tt $i:tt [$arity_first:tt $($arity:tt)*] [$index_first:tt $($index:tt)*] $($out:tt)*) => ($crate::__sum_check!($n $i [$($arity)*] [$($index)*] $($out)*));
+	($n:tt $i:tt [$($arity:tt)*] [] $($out:tt)*) => ($($out)*);
+	($n:tt $i:tt [] [$($index:tt)+] $($out:tt)*) => (compile_error!(concat!("sum!: index ", stringify!($i), " is out of range for ", stringify!($n), " types")));
+}
 
 #[doc(hidden)]
 #[macro_export]
@@ -474,8 +1609,770 @@ fn basic() {
 	assert_eq!(e.as_mut().b(), Some(&mut 2));
 }
 
+#[test]
+fn accessors() {
+	let mut e: Sum3<u8, String, bool> = Sum3::B(String::from("b"));
+	assert_eq!(e.variant_name(), "B");
+	assert_eq!(e.b_ref().map(String::as_str), Some("b"));
+	assert_eq!(e.a_ref(), None);
+	e.b_mut().unwrap().push('c');
+	assert_eq!(e.clone().unwrap_b(), "bc");
+	assert_eq!(e.clone().a_or(1), 1);
+	assert!(e.clone().c_or_else(|other| other.is_b()));
+	assert!(e.clone().is_b_and(|b| b.len() == 2));
+	assert!(!e.clone().is_a_and(|_| true));
+	assert_eq!(e.replace_c(false), Sum3::B(String::from("bc")));
+	assert!(!e.clone().expect_c("c"));
+	let panic = std::panic::catch_unwind(|| Sum2::<u8, u8>::A(0).unwrap_b()).unwrap_err();
+	assert_eq!(
+		panic.downcast_ref::<String>().unwrap(),
+		"called `Sum2::unwrap_b()` on a `A` value"
+	);
+}
+
+#[test]
+fn conversions() {
+	assert_eq!(Sum1::from(5), Sum1::A(5));
+	assert_eq!(Sum1::from(5).into_inner(), 5);
+	assert_eq!(Sum2::from(Ok::<u8, ()>(1)), Sum2::A(1));
+	assert_eq!(std::result::Result::from(Sum2::<u8, ()>::B(())), Err(()));
+	assert_eq!(Sum2::from(None::<u8>), Sum2::B(()));
+	assert_eq!(Option::from(Sum2::<u8, ()>::A(1)), Some(1));
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn either() {
+	use either::Either;
+	assert_eq!(Sum2::from(Either::<u8, bool>::Right(true)), Sum2::B(true));
+	assert_eq!(Either::from(Sum2::<u8, bool>::A(1)), Either::Left(1));
+	let nested: Either<u8, Either<bool, char>> = Either::Right(Either::Right('c'));
+	assert_eq!(Sum3::from(nested), Sum3::C('c'));
+	assert_eq!(Either::from(Sum3::<u8, bool, char>::B(true)), Either::<u8, Either<bool, char>>::Right(Either::Left(true)));
+}
+
+#[cfg(feature = "serde-representations")]
+#[test]
+fn serde_representations() {
+	#[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+	struct Point {
+		x: i32,
+		y: i32,
+	}
+	enum TypeValue {}
+	impl serde_repr::Keys for TypeValue {
+		const TAG: &'static str = "type";
+		const CONTENT: &'static str = "value";
+	}
+	#[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+	struct Keyed(
+		#[serde(
+			serialize_with = "crate::serde_repr::adjacent::serialize_with_keys::<TypeValue, _, _>",
+			deserialize_with = "crate::serde_repr::adjacent::deserialize_with_keys::<TypeValue, _, _>"
+		)]
+		Sum2<u8, bool>,
+	);
+	#[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+	struct Message {
+		#[serde(with = "crate::serde_repr::untagged")]
+		untagged: Sum3<u32, bool, String>,
+		#[serde(with = "crate::serde_repr::indexed")]
+		indexed: Sum3<u32, bool, String>,
+		#[serde(with = "crate::serde_repr::adjacent")]
+		adjacent: Sum3<u32, bool, String>,
+		#[serde(with = "crate::serde_repr::internal")]
+		internal: Sum2<Point, std::collections::BTreeMap<String, u8>>,
+	}
+	let message = Message {
+		untagged: Sum3::C(String::from("c")),
+		indexed: Sum3::B(true),
+		adjacent: Sum3::A(5),
+		internal: Sum2::A(Point { x: 1, y: 2 }),
+	};
+	let json = serde_json::to_string(&message).unwrap();
+	assert_eq!(
+		json,
+		r#"{"untagged":"c","indexed":[1,true],"adjacent":{"tag":"A","content":5},"internal":{"tag":"A","x":1,"y":2}}"#
+	);
+	assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+	let reordered = r#"{"untagged":5,"indexed":[2,"c"],"adjacent":{"content":true,"tag":"B"},"internal":{"b":1,"tag":"B"}}"#;
+	let message = serde_json::from_str::<Message>(reordered).unwrap();
+	assert_eq!(message.untagged, Sum3::A(5));
+	assert_eq!(message.indexed, Sum3::C(String::from("c")));
+	assert_eq!(message.adjacent, Sum3::B(true));
+	assert_eq!(message.internal.b().unwrap()["b"], 1);
+	assert!(serde_json::from_str::<Message>(r#"{"untagged":null,"indexed":[3,5],"adjacent":{"tag":"D","content":5},"internal":{}}"#).is_err());
+	let keyed = serde_json::to_string(&Keyed(Sum2::B(false))).unwrap();
+	assert_eq!(keyed, r#"{"type":"B","value":false}"#);
+	assert_eq!(serde_json::from_str::<Keyed>(&keyed).unwrap(), Keyed(Sum2::B(false)));
+}
+
+#[cfg(feature = "serde-representations")]
+#[test]
+fn serde_untagged() {
+	use serde_repr::Untagged;
+	fn round_trip<T>(value: &T, json: &str)
+	where
+		T: serde_repr::SerializeVariants + for<'de> serde_repr::DeserializeVariants<'de> + PartialEq + Debug,
+	{
+		assert_eq!(serde_json::to_string(&Untagged(value)).unwrap(), json);
+		assert_eq!(&serde_json::from_str::<Untagged<T>>(json).unwrap().0, value);
+	}
+	fn error<T>(json: &str) -> String
+	where
+		T: for<'de> serde_repr::DeserializeVariants<'de> + Debug,
+	{
+		serde_json::from_str::<Untagged<T>>(json).unwrap_err().to_string()
+	}
+	round_trip(&Sum1::<u8>::A(1), "1");
+	round_trip(&Sum2::<u8, String>::B(String::from("b")), r#""b""#);
+	round_trip(&Sum3::<u8, String, bool>::C(true), "true");
+	round_trip(&Sum4::<u8, String, bool, Vec<u8>>::D(vec![1, 2]), "[1,2]");
+	assert_eq!(Sum2::<u8, u16>::A(1), serde_json::from_str::<Untagged<_>>("1").unwrap().0);
+	assert_eq!(Sum2::<u8, u16>::B(256), serde_json::from_str::<Untagged<_>>("256").unwrap().0);
+	assert_eq!(
+		error::<Sum1<u8>>("-1"),
+		"data did not match any variant of untagged sum: A: invalid value: integer `-1`, expected u8"
+	);
+	assert_eq!(
+		error::<Sum2<u8, bool>>(r#""x""#),
+		r#"data did not match any variant of untagged sum: A: invalid type: string "x", expected u8; B: invalid type: string "x", expected a boolean"#
+	);
+	assert_eq!(
+		error::<Sum3<u8, bool, String>>("[]"),
+		"data did not match any variant of untagged sum: A: invalid type: sequence, expected u8; B: invalid type: sequence, expected a boolean; C: invalid type: sequence, expected a string"
+	);
+	assert_eq!(
+		error::<Sum4<u8, bool, String, ()>>("1.5"),
+		"data did not match any variant of untagged sum: A: invalid type: floating point `1.5`, expected u8; B: invalid type: floating point `1.5`, expected a boolean; C: invalid type: floating point `1.5`, expected a string; D: invalid type: floating point `1.5`, expected unit"
+	);
+}
+
+#[cfg(feature = "serde-representations")]
+#[test]
+fn serde_buffered() {
+	use serde_repr::Untagged;
+	#[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+	struct Named<'a> {
+		zone: u8,
+		name: &'a str,
+	}
+	#[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+	struct Internal<'a>(#[serde(borrow, with = "crate::serde_repr::internal")] Sum2<Named<'a>, Vec<u8>>);
+	#[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+	struct Adjacent<'a>(#[serde(borrow, with = "crate::serde_repr::adjacent")] Sum2<u8, &'a str>);
+
+	// Borrowed variants deserialize despite the input being buffered.
+	let untagged: Untagged<Sum2<u8, &str>> = serde_json::from_str(r#""borrowed""#).unwrap();
+	assert_eq!(untagged.0, Sum2::B("borrowed"));
+	let adjacent: Adjacent = serde_json::from_str(r#"{"content":"borrowed","tag":"B"}"#).unwrap();
+	assert_eq!(adjacent.0, Sum2::B("borrowed"));
+
+	// Internally tagged fields keep their order, after the tag.
+	let internal = Internal(Sum2::A(Named { zone: 1, name: "x" }));
+	let json = serde_json::to_string(&internal).unwrap();
+	assert_eq!(json, r#"{"tag":"A","zone":1,"name":"x"}"#);
+	assert_eq!(serde_json::from_str::<Internal>(&json).unwrap(), internal);
+	assert_eq!(serde_json::from_str::<Internal>(r#"{"name":"x","tag":"A","zone":1}"#).unwrap(), internal);
+	assert_eq!(
+		serde_json::to_string(&Internal(Sum2::B(vec![1]))).unwrap_err().to_string(),
+		"internally tagged sum can only hold maps or structs, but variant B holds a sequence"
+	);
+
+	// Externally tagged values, such as nested sums, replay from the buffer too.
+	let nested: Untagged<Sum2<u8, Sum2<bool, String>>> = serde_json::from_str(r#"{"B":"nested"}"#).unwrap();
+	assert_eq!(nested.0, Sum2::B(Sum2::B(String::from("nested"))));
+}
+
+#[cfg(feature = "from-str")]
+#[test]
+fn from_str() {
+	use std::{
+		path::PathBuf, sync::atomic::{AtomicUsize, Ordering}
+	};
+	static PARSES: AtomicUsize = AtomicUsize::new(0);
+	#[derive(PartialEq, Debug)]
+	struct Counted(u8);
+	impl FromStr for Counted {
+		type Err = std::num::ParseIntError;
+		fn from_str(src: &str) -> std::result::Result<Self, Self::Err> {
+			let _ = PARSES.fetch_add(1, Ordering::Relaxed);
+			src.parse().map(Counted)
+		}
+	}
+	let port: Sum3<u16, bool, PathBuf> = "8080".parse().unwrap();
+	assert_eq!(port, Sum3::A(8080));
+	let path: Sum3<u16, bool, PathBuf> = "/tmp/socket".parse().unwrap();
+	assert_eq!(path, Sum3::C(PathBuf::from("/tmp/socket")));
+	let err = "x".parse::<Sum2<u8, bool>>().unwrap_err();
+	assert_eq!(
+		err.to_string(),
+		"string did not match any variant: A: invalid digit found in string; B: provided string was not `true` or `false`"
+	);
+	assert!(Sum0::from_str("").is_err());
+	assert_eq!(Sum2::<u8, bool>::from_str_strict("true"), Ok(Sum2::B(true)));
+	assert_eq!(
+		Sum3::<u8, u16, bool>::from_str_strict("1"),
+		Err(StrictFromStrError::Ambiguous(vec!["A", "B"]))
+	);
+	assert_eq!(
+		Sum3::<u8, u16, bool>::from_str_strict("1")
+			.unwrap_err()
+			.to_string(),
+		"string matched more than one variant: A, B"
+	);
+	assert!(matches!(
+		Sum1::<u8>::from_str_strict("x"),
+		Err(StrictFromStrError::NoMatch(_))
+	));
+	assert_eq!(Sum2::<Counted, bool>::from_str_strict("1"), Ok(Sum2::A(Counted(1))));
+	assert!(matches!(
+		Sum2::<bool, Counted>::from_str_strict("x"),
+		Err(StrictFromStrError::NoMatch(FromStrError((_, _))))
+	));
+	assert_eq!(PARSES.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn default() {
+	#[derive(Default)]
+	struct Config {
+		value: Sum3<u16, String, bool>,
+	}
+	assert_eq!(Config::default().value, Sum3::A(0));
+	assert_eq!(Sum3::<u16, String, bool>::default_b(), Sum3::B(String::new()));
+	assert_eq!(Sum3::<u16, String, bool>::default_c(), Sum3::C(false));
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn ops() {
+	let samples: [Sum3<i16, i32, f32>; 3] = [Sum3::A(2), Sum3::B(-3), Sum3::C(0.5)];
+	let negated: Vec<_> = samples.iter().map(|&sample| -sample).collect();
+	assert_eq!(negated, [Sum3::A(-2), Sum3::B(3), Sum3::C(-0.5)]);
+	assert_eq!(!Sum2::<bool, u8>::A(true), Sum2::A(false));
+	assert_eq!(!Sum2::<bool, u8>::B(0x0f), Sum2::B(0xf0));
+
+	let mut sample = Sum2::<u32, i64>::B(-10);
+	sample <<= 2_u8;
+	sample >>= 1_u8;
+	assert_eq!(sample, Sum2::B(-20));
+	let mut text = Sum2::<String, Cow<str>>::B("sum".into());
+	text += " type";
+	assert_eq!(&*text, "sum type");
+
+	let sample = Sum2::<u8, &u8>::B(&3);
+	assert_eq!(sample + 4_u8, 7_u8);
+	assert_eq!(Sum2::<u8, &u8>::A(12) % 5, 2);
+	assert_eq!(Sum2::<u8, &u8>::A(0b1100) & 0b1010, 0b1000);
+}
+
+#[cfg(feature = "call")]
+#[test]
+fn call() {
+	fn double(x: u32) -> u32 {
+		x * 2
+	}
+	let offset = 10;
+	let callbacks: Vec<Sum2<fn(u32) -> u32, Box<dyn Fn(u32) -> u32>>> =
+		vec![Sum2::A(double), Sum2::B(Box::new(move |x| x + offset))];
+	assert_eq!(callbacks[0].call(4), 8);
+	assert_eq!(callbacks[1].call(4), 14);
+
+	let mut count = 0;
+	let mut counter = Sum2::<_, fn(u32) -> u32>::A(|x: u32| {
+		count += x;
+		count
+	});
+	assert_eq!(counter.call_mut(3), 3);
+	assert_eq!(counter.call_mut(3), 6);
+
+	let name = String::from("sum");
+	assert_eq!(Sum2::<_, fn(()) -> String>::A(move |()| name).call_once(()), "sum");
+
+	let square = |x: u32| x * x;
+	let chosen = if offset > 5 { Sum2::A(square) } else { Sum2::B(double) };
+	let mapped: Vec<u32> = (1..4).map(chosen.into_fn()).collect();
+	assert_eq!(mapped, [1, 4, 9]);
+}
+
+#[cfg(feature = "any")]
+#[test]
+fn any() {
+	type Message = Sum3<u32, String, Vec<u8>>;
+	let payloads: Vec<Box<dyn Any + Send>> = vec![Box::new(String::from("hello")), Box::new(7_u32), Box::new(1.5_f64)];
+	let mut messages = payloads.into_iter().map(Message::from_any_send);
+	assert_eq!(messages.next().unwrap().unwrap(), Sum3::B(String::from("hello")));
+	assert_eq!(messages.next().unwrap().unwrap(), Sum3::A(7));
+	let rejected = messages.next().unwrap().unwrap_err();
+	assert_eq!(rejected.downcast_ref::<f64>(), Some(&1.5));
+
+	let Err(boxed) = Message::from_any(Box::new('x')) else {
+		panic!("char is not a variant")
+	};
+	assert!(boxed.is::<char>());
+
+	let mut message = Message::C(vec![1, 2]);
+	assert_eq!(message.type_id_of_active(), TypeId::of::<Vec<u8>>());
+	assert_eq!(message.as_any().downcast_ref::<Vec<u8>>(), Some(&vec![1, 2]));
+	message.as_any_mut().downcast_mut::<Vec<u8>>().unwrap().push(3);
+	assert_eq!(message, Sum3::C(vec![1, 2, 3]));
+}
+
+#[cfg(feature = "dyn")]
+#[test]
+fn as_dyn() {
+	use std::any::Any;
+	fn describe(value: &dyn Debug) -> String {
+		format!("{value:?}")
+	}
+	let mut value = Sum3::<u8, String, Vec<u8>>::C(vec![1]);
+	assert_eq!(describe(value.as_dyn()), "[1]");
+	assert_eq!(value.as_dyn::<dyn Any>().downcast_ref::<Vec<u8>>(), Some(&vec![1]));
+	value.as_dyn_mut::<dyn Any>().downcast_mut::<Vec<u8>>().unwrap().push(2);
+	let boxed: Box<dyn Debug + Send> = value.into_dyn();
+	assert_eq!(format!("{boxed:?}"), "[1, 2]");
+
+	let err = Sum2::<std::num::ParseIntError, std::str::ParseBoolError>::B("x".parse::<bool>().unwrap_err());
+	let boxed: Box<dyn Error + Send + Sync> = err.into_dyn();
+	assert_eq!(boxed.to_string(), "provided string was not `true` or `false`");
+}
+
+#[test]
+fn layout() {
+	use std::{
+		mem::size_of, num::NonZeroU32, ptr::NonNull
+	};
+	assert_eq!(size_of::<Sum2<&u64, ()>>(), size_of::<&u64>());
+	assert_eq!(size_of::<Sum2<Box<str>, ()>>(), size_of::<Box<str>>());
+	assert_eq!(size_of::<Sum2<NonNull<u8>, ()>>(), size_of::<usize>());
+	assert_eq!(size_of::<Option<Sum2<&u8, &u16>>>(), size_of::<Sum2<&u8, &u16>>());
+	assert_eq!(size_of::<Sum1<NonZeroU32>>(), 4);
+	assert_eq!(size_of::<Option<Sum1<NonZeroU32>>>(), 4);
+	assert_eq!(size_of::<Sum3<bool, (), ()>>(), 1);
+
+	#[cfg(feature = "repr-c")]
+	{
+		assert_eq!(size_of::<repr_c::Sum2<&u64, ()>>(), 2 * size_of::<usize>());
+		assert_eq!(size_of::<repr_c::Sum1<u8>>(), 2);
+		assert_eq!(size_of::<repr_c::Sum3<u8, u16, [u8; 3]>>(), 6);
+		assert_eq!(align_of::<repr_c::Sum3<u8, u16, [u8; 3]>>(), 2);
+		let sums: Vec<repr_c::Sum3<u8, u16, u32>> = vec![repr_c::Sum3::A(1), repr_c::Sum3::B(2), repr_c::Sum3::C(3)];
+		for (index, sum) in sums.into_iter().enumerate() {
+			let tag = unsafe { *std::ptr::addr_of!(sum).cast::<u8>() };
+			assert_eq!(usize::from(tag), index);
+			assert_eq!(Sum3::from(sum).variant_index(), index);
+		}
+		assert_eq!(repr_c::Sum2::from(Sum2::<u8, bool>::B(true)), repr_c::Sum2::B(true));
+	}
+}
+
+#[test]
+fn packed() {
+	use std::{mem::size_of, rc::Rc, sync::Arc};
+	type Node<'a> = packed::Sum4<Box<u64>, Box<String>, Arc<u32>, &'a u32>;
+	assert_eq!(size_of::<Node>(), size_of::<usize>());
+	assert_eq!(size_of::<Option<Node>>(), size_of::<usize>());
+
+	let short = 5;
+	let nodes: Vec<Node> = vec![
+		Node::new(Sum4::A(Box::new(1))),
+		Node::new(Sum4::B(Box::new(String::from("two")))),
+		Node::new(Sum4::C(Arc::new(3))),
+		Node::from(Sum4::D(&short)),
+	];
+	let indices: Vec<usize> = nodes.iter().map(packed::Sum4::variant_index).collect();
+	assert_eq!(indices, [0, 1, 2, 3]);
+	assert_eq!(nodes[1].as_ref(), Sum4::B(&String::from("two")));
+	assert_eq!(nodes[3].as_ref(), Sum4::D(&5));
+	assert_eq!(format!("{:?}", nodes[2]), "packed::Sum4(C(3))");
+	let sums: Vec<Sum4<Box<u64>, Box<String>, Arc<u32>, &u32>> = nodes.into_iter().map(Sum4::from).collect();
+	assert_eq!(sums[0], Sum4::A(Box::new(1)));
+
+	let mut owned = packed::Sum2::<Box<u32>, &mut u64>::new(Sum2::A(Box::new(1)));
+	if let Sum2::A(value) = owned.as_mut() {
+		*value += 1;
+	}
+	assert_eq!(owned.into_sum(), Sum2::A(Box::new(2)));
+
+	let shared = Rc::new(7_u32);
+	let sum = packed::Sum3::<Rc<u32>, Box<u32>, Arc<u64>>::new(Sum3::A(shared.clone()));
+	assert_eq!(Rc::strong_count(&shared), 2);
+	let copy = sum.clone();
+	assert_eq!(Rc::strong_count(&shared), 3);
+	drop(sum);
+	drop(copy);
+	assert_eq!(Rc::strong_count(&shared), 1);
+
+	// Cloning and formatting only borrow the pointee, so borrows from `as_ref` stay valid across them, as Miri checks.
+	let boxed = packed::Sum2::<Box<u64>, Box<u32>>::new(Sum2::A(Box::new(8)));
+	let borrowed = boxed.as_ref();
+	assert_eq!(format!("{boxed:?}"), "packed::Sum2(A(8))");
+	let copy = boxed.clone();
+	assert_eq!(borrowed, Sum2::A(&8));
+	assert_eq!(copy.as_ref(), Sum2::A(&8));
+	let counted = packed::Sum2::<Rc<u64>, Arc<u32>>::new(Sum2::B(Arc::new(9)));
+	let borrowed = counted.as_ref();
+	let copy = counted.clone();
+	assert_eq!(format!("{counted:?}"), "packed::Sum2(B(9))");
+	assert_eq!(borrowed, Sum2::B(&9));
+	drop(counted);
+	assert_eq!(copy.into_sum(), Sum2::B(Arc::new(9)));
+}
+
+#[cfg(feature = "sum-vec")]
+#[test]
+fn sum_vec() {
+	let mut vec: SumVec3<u8, String, [u64; 4]> = vec![Sum3::A(1), Sum3::B(String::from("two")), Sum3::C([3; 4]), Sum3::A(4)]
+		.into_iter()
+		.collect();
+	vec.push(Sum3::B(String::from("five")));
+	assert_eq!(vec.len(), 5);
+	assert_eq!(vec.as_slices(), (&[1, 4][..], &[String::from("two"), String::from("five")][..], &[[3; 4]][..]));
+	let strings: Vec<String> = vec.iter().map(|sum| format!("{sum:?}")).collect();
+	assert_eq!(strings, ["A(1)", "B(\"two\")", "C([3, 3, 3, 3])", "A(4)", "B(\"five\")"]);
+
+	for sum in vec.iter_mut() {
+		if let Sum3::A(a) = sum {
+			*a *= 10;
+		}
+	}
+	vec.retain(|sum| !sum.is_c() && sum != Sum3::B(&String::from("two")));
+	assert_eq!(format!("{vec:?}"), "[A(10), A(40), B(\"five\")]");
+	assert_eq!(vec.pop(), Some(Sum3::B(String::from("five"))));
+	assert_eq!(vec.pop(), Some(Sum3::A(40)));
+	vec.clear();
+	assert!(vec.is_empty());
+	assert_eq!(vec.pop(), None);
+
+	// A panicking predicate leaves the elements visited and kept, followed by those not yet visited.
+	let mut vec: SumVec2<u8, u16> = vec![Sum2::A(1), Sum2::B(2), Sum2::A(3), Sum2::B(4), Sum2::A(5)].into_iter().collect();
+	let mut calls = 0;
+	let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		vec.retain(|sum| {
+			calls += 1;
+			assert!(calls < 4, "predicate panicked");
+			match sum {
+				Sum2::A(a) => a % 2 == 1,
+				Sum2::B(b) => b % 2 == 1,
+			}
+		});
+	}));
+	assert!(result.is_err());
+	assert_eq!(vec.len(), 4);
+	assert_eq!(vec.as_slices(), (&[1, 3, 5][..], &[4][..]));
+	assert_eq!(format!("{vec:?}"), "[A(1), A(3), B(4), A(5)]");
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn rayon() {
+	use ::rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator};
+	let values: Vec<u64> = (1..=100).collect();
+	for bridge in [false, true] {
+		let iter = if bridge {
+			Sum2::B(values.iter().copied().par_bridge())
+		} else {
+			Sum2::A(values.par_iter().copied())
+		};
+		assert_eq!(iter.sum::<u64>(), 5050);
+	}
+
+	let doubled: Vec<u64> = values.iter().map(|value| value * 2).collect();
+	for reversed in [false, true] {
+		let iter = if reversed {
+			Sum2::B(doubled.par_iter().rev())
+		} else {
+			Sum2::A(values.par_iter())
+		};
+		assert_eq!(iter.len(), 100);
+		let mut collected = Vec::new();
+		iter.enumerate().map(|(index, value)| (index, *value)).collect_into_vec(&mut collected);
+		assert_eq!(collected[0], if reversed { (0, 200) } else { (0, 1) });
+		assert_eq!(collected.len(), 100);
+	}
+}
+
+#[test]
+fn error() {
+	fn parse(src: &str) -> std::result::Result<u8, SumError<Sum2<std::num::ParseIntError, std::str::ParseBoolError>>> {
+		let flag: bool = src.parse().map_err(|err| SumError::with_context(Sum2::B(err), "parsing flag"))?;
+		let value: u8 = src.parse().map_err(Sum2::A)?;
+		Ok(value + u8::from(flag))
+	}
+	let err = Sum2::<std::num::ParseIntError, std::str::ParseBoolError>::A("x".parse::<u8>().unwrap_err());
+	assert_eq!(err.display_with_variant().to_string(), "A: invalid digit found in string");
+
+	let err = parse("x").unwrap_err();
+	assert_eq!(err.context(), Some("parsing flag"));
+	assert_eq!(err.to_string(), "parsing flag, in variant B of Sum2");
+	assert_eq!(err.source().unwrap().to_string(), "provided string was not `true` or `false`");
+	let err = SumError::from(Sum1::from(err.into_inner().b().unwrap()));
+	assert_eq!(err.to_string(), "error in variant A of Sum1");
+	assert!(err.source().is_some());
+}
+
+#[test]
+fn error_union() {
+	error_union!(
+		/// Errors from `parse`.
+		pub(crate) ParseError(std::num::ParseIntError, std::str::ParseBoolError, std::io::Error)
+	);
+	fn parse(src: &str) -> std::result::Result<(u8, bool), ParseError> {
+		let (int, flag) = src.split_once(' ').ok_or_else(|| std::io::Error::other("missing space"))?;
+		Ok((int.parse()?, flag.parse()?))
+	}
+	assert_eq!(parse("7 true").unwrap(), (7, true));
+
+	let err = parse("7").unwrap_err();
+	assert!(err.0.is_c());
+	assert_eq!(format!("{err:?}"), format!("{:?}", std::io::Error::other("missing space")));
+	let err = parse("x true").unwrap_err();
+	assert_eq!(format!("{err:?}"), "ParseIntError { kind: InvalidDigit }");
+	assert_eq!(err.to_string(), "invalid digit found in string");
+	let err = parse("7 x").unwrap_err();
+	assert_eq!(err.to_string(), "provided string was not `true` or `false`");
+	assert!(Sum3::from(err).is_b());
+}
+
+#[test]
+fn formatting() {
+	#[cfg(feature = "fmt")]
+	{
+		let values: [Sum3<u8, u16, u32>; 3] = [Sum3::A(0xab), Sum3::B(0x1234), Sum3::C(0xdead_beef)];
+		let hex: Vec<String> = values.iter().map(|value| format!("{value:#x}")).collect();
+		assert_eq!(hex, ["0xab", "0x1234", "0xdeadbeef"]);
+		assert_eq!(format!("{:X}", values[2]), "DEADBEEF");
+		assert_eq!(format!("{:o}", values[0]), "253");
+		assert_eq!(format!("{:08b}", values[0]), "10101011");
+		let float = Sum2::<f32, f64>::B(1500.0);
+		assert_eq!(format!("{float:e}"), "1.5e3");
+		assert_eq!(format!("{float:E}"), "1.5E3");
+		let byte = 7_u8;
+		let pointer = Sum2::<&u8, *const u16>::A(&byte);
+		assert_eq!(format!("{pointer:p}"), format!("{:p}", &byte));
+	}
+	let sum = Sum2::<u8, &str>::B("x");
+	assert_eq!(format!("{sum:?}"), "B(\"x\")");
+	assert_eq!(format!("{:?}", fmt::Transparent(sum)), "\"x\"");
+	assert_eq!(format!("{:?}", fmt::Transparent(&Sum2::<u8, &str>::A(1))), "1");
+	assert_eq!(format!("{:#?}", fmt::Transparent(Sum1::A((1, 2)))), "(\n    1,\n    2,\n)");
+}
+
+#[test]
+fn by_value() {
+	use std::collections::{BTreeSet, HashMap};
+	type Name = ByValue<Sum2<String, Box<str>>, str>;
+	type Id = ByKey<Sum2<u32, u64>, u64>;
+	let owned: Name = ByValue::new(Sum2::A(String::from("bob")));
+	let boxed = Name::from(Sum2::B("bob".into()));
+	assert_eq!(owned, boxed);
+	assert_ne!(owned.get_ref(), boxed.get_ref());
+
+	let mut counts: HashMap<Name, u32> = HashMap::new();
+	for name in [Sum2::A(String::from("bob")), Sum2::B("alice".into()), Sum2::B("bob".into())] {
+		*counts.entry(ByValue::new(name)).or_default() += 1;
+	}
+	assert_eq!(counts[&owned], 2);
+
+	let sorted: BTreeSet<Name> = vec![Sum2::B("carol".into()), Sum2::A(String::from("alice")), Sum2::B("bob".into())]
+		.into_iter()
+		.map(ByValue::new)
+		.collect();
+	let sorted: Vec<&str> = sorted.iter().map(|name| name.get_ref().borrow_inner()).collect();
+	assert_eq!(sorted, ["alice", "bob", "carol"]);
+	assert_eq!(format!("{owned:?}"), "ByValue(A(\"bob\"))");
+
+	let mut counts: HashMap<Id, u32> = HashMap::new();
+	for id in [Sum2::A(7), Sum2::B(7), Sum2::B(1 << 40), Sum2::A(3)] {
+		*counts.entry(ByKey::new(id)).or_default() += 1;
+	}
+	assert_eq!(counts[&ByKey::new(Sum2::B(7))], 2);
+	assert_eq!(counts[&ByKey::new(Sum2::B(1 << 40))], 1);
+	assert_eq!(counts.len(), 3);
+	let sorted: BTreeSet<Id> = vec![Sum2::B(1 << 40), Sum2::A(7), Sum2::B(3)].into_iter().map(ByKey::new).collect();
+	let sorted: Vec<u64> = sorted.iter().map(|id| id.get_ref().to_key()).collect();
+	assert_eq!(sorted, [3, 7, 1 << 40]);
+	assert_eq!(format!("{:?}", Id::from(Sum2::A(7))), "ByKey(A(7))");
+}
+
+#[test]
+fn borrow() {
+	use std::collections::HashSet;
+	fn words(src: &str) -> Vec<Sum2<&str, String>> {
+		src.split(' ').map(|word| if word.contains('_') { Sum2::B(word.replace('_', " ")) } else { Sum2::A(word) }).collect()
+	}
+	let mut seen: HashSet<ByValue<Sum2<String, Box<str>>, str>> = HashSet::new();
+	assert!(seen.insert(ByValue::new(Sum2::B("x".into()))));
+	assert!(seen.contains("x"));
+	assert!(!seen.contains("y"));
+
+	let parsed = words("zero copy_parsing");
+	assert_eq!(parsed, [Sum2::A("zero"), Sum2::B(String::from("copy parsing"))]);
+	let owned: Vec<String> = parsed.iter().cloned().map(Sum2::into_owned).collect();
+	assert_eq!(owned, ["zero", "copy parsing"]);
+	let cows: Vec<Cow<str>> = parsed.into_iter().map(Cow::from).collect();
+	assert!(matches!(cows[0], Cow::Borrowed("zero")));
+	assert_eq!(Sum2::from(cows[1].clone()), Sum2::B(String::from("copy parsing")));
+	assert_eq!(Sum2::<u32, u64>::A(7).into_owned::<u64>(), 7);
+}
+
+#[test]
+fn pin_projection() {
+	use std::{
+		cell::Cell, future::{pending, ready, Ready}, rc::Rc, task::{RawWaker, RawWakerVTable, Waker}
+	};
+	struct SetOnDrop(Rc<Cell<bool>>);
+	impl Drop for SetOnDrop {
+		fn drop(&mut self) {
+			self.0.set(true);
+		}
+	}
+	fn noop_waker() -> Waker {
+		fn clone(_: *const ()) -> RawWaker {
+			RawWaker::new(std::ptr::null(), &VTABLE)
+		}
+		fn noop(_: *const ()) {}
+		static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+		unsafe { Waker::from_raw(clone(std::ptr::null())) }
+	}
+	let waker = noop_waker();
+	let mut cx = Context::from_waker(&waker);
+
+	// A state machine that waits on a first future, then a second built from its output.
+	let mut state = Box::pin(Sum2::A(async { 20_u32 }));
+	let first = state.as_mut().as_pin_mut_a().unwrap().poll(&mut cx);
+	assert_eq!(first, Poll::Ready(20));
+	assert!(state.as_mut().as_pin_mut_b().is_none());
+	state.as_mut().set_b(ready(first.map(|value| value + 1)));
+	assert!(state.as_ref().as_pin_ref_a().is_none());
+	assert!(state.as_ref().as_pin_ref_b().is_some());
+	assert_eq!(state.as_mut().as_pin_mut_b().unwrap().poll(&mut cx), Poll::Ready(Poll::Ready(21)));
+
+	// Replacing a pinned, `!Unpin` variant drops it in place.
+	let dropped = Rc::new(Cell::new(false));
+	let guard = SetOnDrop(dropped.clone());
+	let mut state = Box::pin(Sum2::A(async move {
+		let _guard = guard;
+		pending::<u32>().await
+	}));
+	assert!(state.as_mut().as_pin_mut_a().unwrap().poll(&mut cx).is_pending());
+	let replaced = state.as_mut().project_replace(Sum2::B(ready(3)));
+	assert!(replaced.is_a());
+	assert!(dropped.get());
+	assert_eq!(state.as_mut().as_pin_mut_b().unwrap().poll(&mut cx), Poll::Ready(3));
+
+	// An `Unpin` sum gives back the value it replaced.
+	let mut state = Box::pin(Sum2::<_, Ready<u32>>::A(ready(4)));
+	let replaced = state.as_mut().replace(Sum2::B(ready(5)));
+	assert_eq!(replaced.a().unwrap().into_inner(), 4);
+	assert_eq!(state.as_mut().as_pin_mut_b().unwrap().poll(&mut cx), Poll::Ready(5));
+}
+
+#[test]
+fn reference_sums() {
+	let sum = Sum3::<u8, String, Vec<u8>>::B(String::from("sum"));
+	assert_eq!(sum.as_ref().cloned(), sum);
+	let number = Sum2::<u8, u16>::B(7);
+	assert_eq!(number.as_ref().copied(), number);
+
+	let mut sum = sum;
+	if let Sum3::B(text) = sum.as_mut() {
+		text.push('s');
+	}
+	assert_eq!(sum.as_mut().cloned(), Sum3::B(String::from("sums")));
+	let mut number = number;
+	assert_eq!(number.as_mut().copied(), Sum2::B(7));
+
+	let text = Sum2::<String, Box<str>>::A(String::from("sum"));
+	assert_eq!(text.as_deref(), Sum2::A("sum"));
+	let mut bytes = Sum2::<Box<[u8]>, Vec<u8>>::B(vec![1, 2]);
+	assert_eq!(bytes.as_deref(), Sum2::B(&[1, 2][..]));
+	if let Sum2::B(slice) = bytes.as_deref_mut() {
+		slice[0] = 3;
+	}
+	assert_eq!(bytes, Sum2::B(vec![3, 2]));
+}
+
+#[test]
+fn macros() {
+	let a: Sum![u8, String, bool] = sum!(2 of 3: true);
+	assert_eq!(a, Sum3::C(true));
+	let b: Sum![u8] = sum!(0 of 1: 5);
+	assert_eq!(b, Sum1::A(5));
+	let c: Option<Sum![]> = None;
+	assert!(c.is_none());
+}
+
+#[test]
+fn cons() {
+	let a: Cons![u8, String, bool] = Sum3::<u8, String, bool>::B(String::from("b")).into();
+	assert_eq!(a, Cons::Tail(Cons::Head(String::from("b"))));
+	assert_eq!(Sum3::from(a), Sum3::<u8, String, bool>::B(String::from("b")));
+	let mut b: Cons![std::vec::IntoIter<u8>, std::option::IntoIter<u8>] = Cons::Tail(Cons::Head(Some(1).into_iter()));
+	assert_eq!(b.next(), Some(1));
+	assert_eq!(b.next(), None);
+}
+
+#[test]
+fn cons_long() {
+	#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+	#[derive(PartialEq, Debug)]
+	struct Leaf(u32);
+	impl Display for Leaf {
+		fn fmt(&self, f: &mut Formatter) -> Result {
+			write!(f, "leaf {}", self.0)
+		}
+	}
+	impl Error for Leaf {}
+	#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+	#[derive(PartialEq, Debug)]
+	struct Branch(Leaf);
+	impl Display for Branch {
+		fn fmt(&self, f: &mut Formatter) -> Result {
+			write!(f, "branch")
+		}
+	}
+	impl Error for Branch {
+		fn source(&self) -> Option<&(dyn Error + 'static)> {
+			Some(&self.0)
+		}
+	}
+	// 40 types, past the 32 `SumN` goes up to.
+	type Long = Cons![
+		Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf, Leaf,
+		Branch
+	];
+	macro_rules! tail {
+		([] $e:expr) => ($e);
+		([_ $($depth:tt)*] $e:expr) => (Cons::Tail(tail!([$($depth)*] $e)));
+	}
+
+	let first: Long = Cons::Head(Leaf(1));
+	assert!(first.is_head());
+	assert_eq!(first.to_string(), "leaf 1");
+	assert!(first.source().is_none());
+
+	let last: Long = tail!([_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] Cons::Head(Branch(Leaf(40))));
+	assert!(last.is_tail());
+	assert_eq!(last.to_string(), "branch");
+	assert_eq!(last.source().unwrap().to_string(), "leaf 40");
+	assert_ne!(first, last);
+
+	#[cfg(feature = "serde")]
+	{
+		let json = serde_json::to_string(&last).unwrap();
+		assert_eq!(json, format!(r#"{}{{"Head":40}}{}"#, r#"{"Tail":"#.repeat(39), "}".repeat(39)));
+		assert_eq!(serde_json::from_str::<Long>(&json).unwrap(), last);
+		let json = serde_json::to_string(&first).unwrap();
+		assert_eq!(json, r#"{"Head":1}"#);
+		assert_eq!(serde_json::from_str::<Long>(&json).unwrap(), first);
+	}
+}
+
 #[test]
 fn derive() {
+	#[allow(dead_code)]
 	trait Abc {
 		fn abc(&self);
 		fn def(&mut self);
@@ -0,0 +1,274 @@
+//! Sum types of pointers packed into a single tagged pointer.
+//!
+//! `packed::SumN` holds the same values as `SumN` for `N` up to 8, where every variant is a [`Pointer`], but stores the index of the active variant in the low bits of the pointer rather than in a separate tag. `packed::Sum4<Box<A>, Box<B>, Arc<C>, &D>` is one word, where `Sum4` of the same is two.
+//!
+//! This needs as many free low bits as the tag takes: every pointee must be aligned to at least 2 bytes for `Sum2`, 4 for `Sum3` and `Sum4`, and 8 for `Sum5` to `Sum8`. This is checked at compile time, when the sum is constructed.
+//!
+//! ```compile_fail
+//! # use sum::{packed, Sum2};
+//! // `u8` is only aligned to 1 byte, leaving no bit for the tag.
+//! let sum = packed::Sum2::<&u8, &u8>::new(Sum2::A(&1));
+//! ```
+//!
+//! ```
+//! use std::{mem::size_of, sync::Arc};
+//! use sum::{packed, Sum3};
+//!
+//! let sum = packed::Sum3::<Box<u64>, Arc<String>, &u32>::new(Sum3::B(Arc::new(String::from("shared"))));
+//! assert_eq!(size_of::<packed::Sum3<Box<u64>, Arc<String>, &u32>>(), size_of::<usize>());
+//! assert_eq!(sum.as_ref(), Sum3::B(&String::from("shared")));
+//! assert!(sum.into_sum().is_b());
+//! ```
+
+use std::{
+	fmt::{Debug, Formatter, Result}, marker::PhantomData, mem::ManuallyDrop, num::NonZeroUsize, ptr::NonNull, rc::Rc, sync::Arc
+};
+
+/// A pointer that can be converted to and from a raw, thin, non-null pointer to its pointee.
+///
+/// # Safety
+///
+/// `into_raw` must return a pointer to a `Target` aligned to `ALIGN`, that stays valid until it's passed back to `from_raw`, which must restore the original pointer.
+pub unsafe trait Pointer {
+	type Target;
+	/// The alignment of the addresses `into_raw` returns. It must be a power of two.
+	const ALIGN: usize = align_of::<Self::Target>();
+
+	fn into_raw(self) -> NonNull<Self::Target>;
+	/// # Safety
+	///
+	/// `ptr` must have come from `into_raw` on the same type, and not been passed to `from_raw` since.
+	unsafe fn from_raw(ptr: NonNull<Self::Target>) -> Self;
+}
+
+/// A [`Pointer`] that uniquely owns or borrows its pointee, so it may be mutated through.
+///
+/// # Safety
+///
+/// While a value of this type exists, no other pointer may access its pointee.
+pub unsafe trait PointerMut: Pointer {}
+
+/// A [`Pointer`] that can be cloned from its raw form, without reconstructing the original.
+///
+/// # Safety
+///
+/// `clone_raw` must return a pointer equivalent to a clone of the one `ptr` came from, leaving that one valid.
+pub unsafe trait PointerClone: Pointer {
+	/// # Safety
+	///
+	/// `ptr` must have come from `into_raw` on the same type, and not been passed to `from_raw` since.
+	unsafe fn clone_raw(ptr: NonNull<Self::Target>) -> Self;
+}
+
+unsafe impl<T> Pointer for Box<T> {
+	type Target = T;
+
+	fn into_raw(self) -> NonNull<T> {
+		NonNull::from(Box::leak(self))
+	}
+	unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+		Box::from_raw(ptr.as_ptr())
+	}
+}
+unsafe impl<T> PointerMut for Box<T> {}
+unsafe impl<T> PointerClone for Box<T>
+where
+	T: Clone,
+{
+	unsafe fn clone_raw(ptr: NonNull<T>) -> Self {
+		Box::new(ptr.as_ref().clone())
+	}
+}
+unsafe impl<T> Pointer for Rc<T> {
+	type Target = T;
+
+	fn into_raw(self) -> NonNull<T> {
+		// Not via a reference to the pointee, as `from_raw` needs the original pointer's permission to write the reference counts.
+		unsafe { NonNull::new_unchecked(Rc::into_raw(self).cast_mut()) }
+	}
+	unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+		Rc::from_raw(ptr.as_ptr())
+	}
+}
+unsafe impl<T> PointerClone for Rc<T> {
+	unsafe fn clone_raw(ptr: NonNull<T>) -> Self {
+		Rc::increment_strong_count(ptr.as_ptr());
+		Rc::from_raw(ptr.as_ptr())
+	}
+}
+unsafe impl<T> Pointer for Arc<T> {
+	type Target = T;
+
+	fn into_raw(self) -> NonNull<T> {
+		// Not via a reference to the pointee, as `from_raw` needs the original pointer's permission to write the reference counts.
+		unsafe { NonNull::new_unchecked(Arc::into_raw(self).cast_mut()) }
+	}
+	unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+		Arc::from_raw(ptr.as_ptr())
+	}
+}
+unsafe impl<T> PointerClone for Arc<T> {
+	unsafe fn clone_raw(ptr: NonNull<T>) -> Self {
+		Arc::increment_strong_count(ptr.as_ptr());
+		Arc::from_raw(ptr.as_ptr())
+	}
+}
+unsafe impl<T> Pointer for &T {
+	type Target = T;
+
+	fn into_raw(self) -> NonNull<T> {
+		NonNull::from(self)
+	}
+	unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+		ptr.as_ref()
+	}
+}
+unsafe impl<T> PointerClone for &T {
+	unsafe fn clone_raw(ptr: NonNull<T>) -> Self {
+		ptr.as_ref()
+	}
+}
+unsafe impl<T> Pointer for &mut T {
+	type Target = T;
+
+	fn into_raw(self) -> NonNull<T> {
+		NonNull::from(self)
+	}
+	unsafe fn from_raw(mut ptr: NonNull<T>) -> Self {
+		ptr.as_mut()
+	}
+}
+unsafe impl<T> PointerMut for &mut T {}
+
+macro_rules! impl_packed {
+	($name:ident : $align:literal : $($t:ident $index:literal)*) => (
+		/// A single-word tagged pointer holding one of the pointers, like the `SumN` of the same name.
+		pub struct $name<$($t,)*>
+		where
+			$($t: Pointer,)*
+		{
+			ptr: NonNull<()>,
+			marker: PhantomData<crate::$name<$($t,)*>>,
+		}
+		impl<$($t,)*> $name<$($t,)*>
+		where
+			$($t: Pointer,)*
+		{
+			const MASK: usize = $align - 1;
+			const ALIGNED: () = assert!(
+				true $(&& $t::ALIGN >= $align)*,
+				concat!("every pointee of packed::", stringify!($name), " must be aligned to at least ", stringify!($align), " bytes")
+			);
+
+			pub fn new(sum: crate::$name<$($t,)*>) -> Self {
+				#[allow(clippy::let_unit_value)]
+				let () = Self::ALIGNED;
+				let (ptr, tag): (NonNull<()>, usize) = match sum {
+					$(crate::$name::$t(inner) => (inner.into_raw().cast(), $index),)*
+				};
+				$name {
+					ptr: ptr.map_addr(|addr| addr | tag),
+					marker: PhantomData,
+				}
+			}
+			pub fn variant_index(&self) -> usize {
+				self.ptr.addr().get() & Self::MASK
+			}
+			fn untagged(&self) -> NonNull<()> {
+				// The untagged pointer came from `into_raw`, so is non-null.
+				self.ptr.map_addr(|addr| unsafe { NonZeroUsize::new_unchecked(addr.get() & !Self::MASK) })
+			}
+			/// Reconstructs the pointer without giving up ownership of it; the caller must ensure it isn't dropped twice, and that no borrow from `as_ref` or `as_mut` is alive, as reconstructing a `Box` or `&mut T` asserts unique access.
+			unsafe fn read(&self) -> crate::$name<$($t,)*> {
+				match self.variant_index() {
+					$($index => crate::$name::$t($t::from_raw(self.untagged().cast())),)*
+					_ => unreachable!(),
+				}
+			}
+			pub fn into_sum(self) -> crate::$name<$($t,)*> {
+				let this = ManuallyDrop::new(self);
+				unsafe { this.read() }
+			}
+			pub fn as_ref(&self) -> crate::$name<$(&$t::Target,)*> {
+				match self.variant_index() {
+					$($index => crate::$name::$t(unsafe { self.untagged().cast().as_ref() }),)*
+					_ => unreachable!(),
+				}
+			}
+			pub fn as_mut(&mut self) -> crate::$name<$(&mut $t::Target,)*>
+			where
+				$($t: PointerMut,)*
+			{
+				match self.variant_index() {
+					$($index => crate::$name::$t(unsafe { self.untagged().cast().as_mut() }),)*
+					_ => unreachable!(),
+				}
+			}
+		}
+		impl<$($t,)*> Drop for $name<$($t,)*>
+		where
+			$($t: Pointer,)*
+		{
+			fn drop(&mut self) {
+				drop(unsafe { self.read() });
+			}
+		}
+		impl<$($t,)*> Clone for $name<$($t,)*>
+		where
+			$($t: PointerClone,)*
+		{
+			fn clone(&self) -> Self {
+				// Not via `read`, which would materialise a second `Box` or `&mut T` while borrows from `as_ref` may be alive.
+				let sum = match self.variant_index() {
+					$($index => crate::$name::$t(unsafe { $t::clone_raw(self.untagged().cast()) }),)*
+					_ => unreachable!(),
+				};
+				$name::new(sum)
+			}
+		}
+		impl<$($t,)*> Debug for $name<$($t,)*>
+		where
+			$($t: Pointer,)*
+			$($t::Target: Debug,)*
+		{
+			fn fmt(&self, f: &mut Formatter) -> Result {
+				f.debug_tuple(concat!("packed::", stringify!($name))).field(&self.as_ref()).finish()
+			}
+		}
+		unsafe impl<$($t,)*> Send for $name<$($t,)*> where $($t: Pointer + Send,)* {}
+		unsafe impl<$($t,)*> Sync for $name<$($t,)*> where $($t: Pointer + Sync,)* {}
+		impl<$($t,)*> From<crate::$name<$($t,)*>> for $name<$($t,)*>
+		where
+			$($t: Pointer,)*
+		{
+			fn from(sum: crate::$name<$($t,)*>) -> Self {
+				$name::new(sum)
+			}
+		}
+		impl<$($t,)*> From<$name<$($t,)*>> for crate::$name<$($t,)*>
+		where
+			$($t: Pointer,)*
+		{
+			fn from(sum: $name<$($t,)*>) -> Self {
+				sum.into_sum()
+			}
+		}
+	);
+}
+
+#[cfg(feature = "1")]
+impl_packed!(Sum1: 1: A 0);
+#[cfg(feature = "2")]
+impl_packed!(Sum2: 2: A 0 B 1);
+#[cfg(feature = "3")]
+impl_packed!(Sum3: 4: A 0 B 1 C 2);
+#[cfg(feature = "4")]
+impl_packed!(Sum4: 4: A 0 B 1 C 2 D 3);
+#[cfg(feature = "5")]
+impl_packed!(Sum5: 8: A 0 B 1 C 2 D 3 E 4);
+#[cfg(feature = "6")]
+impl_packed!(Sum6: 8: A 0 B 1 C 2 D 3 E 4 F 5);
+#[cfg(feature = "7")]
+impl_packed!(Sum7: 8: A 0 B 1 C 2 D 3 E 4 F 5 G 6);
+#[cfg(feature = "8")]
+impl_packed!(Sum8: 8: A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7);
@@ -0,0 +1,84 @@
+//! Sum types with a stable, C-compatible layout.
+//!
+//! `repr_c::SumN` has the same variants as [`SumN`](../enum.Sum2.html) but is `#[repr(C, u8)]`, and converts to and from it with `From`. Its layout is that of a `#[repr(C)]` struct of two fields:
+//!
+//! * a `u8` tag holding the index of the active variant, `0` for `A`, `1` for `B` and so on;
+//! * a `#[repr(C)]` union of the variants' types.
+//!
+//! So the value starts at the offset of the greatest alignment among the variants, and the size is that offset plus the largest variant, rounded up to that alignment. Unlike `SumN`, no niche in a variant is used to store the tag. There is no `repr_c::Sum0`, as `#[repr(C)]` requires at least one variant.
+//!
+//! ```
+//! use std::mem::{align_of, size_of};
+//! use sum::repr_c;
+//!
+//! let sum = repr_c::Sum2::<u8, u32>::B(7);
+//! assert_eq!(size_of::<repr_c::Sum2<u8, u32>>(), 8);
+//! assert_eq!(align_of::<repr_c::Sum2<u8, u32>>(), 4);
+//! assert_eq!(unsafe { *std::ptr::addr_of!(sum).cast::<u8>() }, 1);
+//! assert_eq!(sum::Sum2::from(sum), sum::Sum2::B(7));
+//! ```
+
+#[cfg(feature = "1")]
+impl_sum!(@repr_c Sum1: A);
+#[cfg(feature = "2")]
+impl_sum!(@repr_c Sum2: A B);
+#[cfg(feature = "3")]
+impl_sum!(@repr_c Sum3: A B C);
+#[cfg(feature = "4")]
+impl_sum!(@repr_c Sum4: A B C D);
+#[cfg(feature = "5")]
+impl_sum!(@repr_c Sum5: A B C D E);
+#[cfg(feature = "6")]
+impl_sum!(@repr_c Sum6: A B C D E F);
+#[cfg(feature = "7")]
+impl_sum!(@repr_c Sum7: A B C D E F G);
+#[cfg(feature = "8")]
+impl_sum!(@repr_c Sum8: A B C D E F G H);
+#[cfg(feature = "9")]
+impl_sum!(@repr_c Sum9: A B C D E F G H I);
+#[cfg(feature = "10")]
+impl_sum!(@repr_c Sum10: A B C D E F G H I J);
+#[cfg(feature = "11")]
+impl_sum!(@repr_c Sum11: A B C D E F G H I J K);
+#[cfg(feature = "12")]
+impl_sum!(@repr_c Sum12: A B C D E F G H I J K L);
+#[cfg(feature = "13")]
+impl_sum!(@repr_c Sum13: A B C D E F G H I J K L M);
+#[cfg(feature = "14")]
+impl_sum!(@repr_c Sum14: A B C D E F G H I J K L M N);
+#[cfg(feature = "15")]
+impl_sum!(@repr_c Sum15: A B C D E F G H I J K L M N O);
+#[cfg(feature = "16")]
+impl_sum!(@repr_c Sum16: A B C D E F G H I J K L M N O P);
+#[cfg(feature = "17")]
+impl_sum!(@repr_c Sum17: A B C D E F G H I J K L M N O P Q);
+#[cfg(feature = "18")]
+impl_sum!(@repr_c Sum18: A B C D E F G H I J K L M N O P Q R);
+#[cfg(feature = "19")]
+impl_sum!(@repr_c Sum19: A B C D E F G H I J K L M N O P Q R S);
+#[cfg(feature = "20")]
+impl_sum!(@repr_c Sum20: A B C D E F G H I J K L M N O P Q R S T);
+#[cfg(feature = "21")]
+impl_sum!(@repr_c Sum21: A B C D E F G H I J K L M N O P Q R S T U);
+#[cfg(feature = "22")]
+impl_sum!(@repr_c Sum22: A B C D E F G H I J K L M N O P Q R S T U V);
+#[cfg(feature = "23")]
+impl_sum!(@repr_c Sum23: A B C D E F G H I J K L M N O P Q R S T U V W);
+#[cfg(feature = "24")]
+impl_sum!(@repr_c Sum24: A B C D E F G H I J K L M N O P Q R S T U V W X);
+#[cfg(feature = "25")]
+impl_sum!(@repr_c Sum25: A B C D E F G H I J K L M N O P Q R S T U V W X Y);
+#[cfg(feature = "26")]
+impl_sum!(@repr_c Sum26: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z);
+#[cfg(feature = "27")]
+impl_sum!(@repr_c Sum27: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z Aa);
+#[cfg(feature = "28")]
+impl_sum!(@repr_c Sum28: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z Aa Ab);
+#[cfg(feature = "29")]
+impl_sum!(@repr_c Sum29: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z Aa Ab Ac);
+#[cfg(feature = "30")]
+impl_sum!(@repr_c Sum30: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z Aa Ab Ac Ad);
+#[cfg(feature = "31")]
+impl_sum!(@repr_c Sum31: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z Aa Ab Ac Ad Ae);
+#[cfg(feature = "32")]
+impl_sum!(@repr_c Sum32: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z Aa Ab Ac Ad Ae Af);
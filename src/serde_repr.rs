@@ -0,0 +1,543 @@
+//! Alternative serde representations of `SumN`.
+//!
+//! The derived impls use serde's default externally tagged representation, so `Sum2::<u32, String>::A(5)` is `{"A":5}` in JSON. The modules here provide other representations, for use with `#[serde(with = "...")]`. They need the `serde-representations` feature, while the `serde` feature alone only derives the default representation:
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! # use sum::*;
+//! #[derive(Serialize, Deserialize)]
+//! struct Message {
+//!     #[serde(with = "sum::serde_repr::untagged")]
+//!     untagged: Sum2<u32, String>, // 5
+//!     #[serde(with = "sum::serde_repr::indexed")]
+//!     indexed: Sum2<u32, String>, // [0,5]
+//!     #[serde(with = "sum::serde_repr::adjacent")]
+//!     adjacent: Sum2<u32, String>, // {"tag":"A","content":5}
+//! }
+//! ```
+//!
+//! The adjacently and internally tagged representations take their field names from a [`Keys`] impl; `adjacent` and `internal` use [`DefaultKeys`], while `serialize_with_keys` and `deserialize_with_keys` in each module take them as a type parameter.
+// Every function here only fails with the error of the `Serializer` or `Deserializer` it was passed.
+#![allow(clippy::missing_errors_doc)]
+
+use ::serde::{
+	de::{self, Deserialize, DeserializeSeed, Deserializer, Error as _, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor}, ser::{Error as _, Impossible, Serialize, SerializeMap, SerializeStruct, SerializeTuple, Serializer}
+};
+use std::{
+	fmt::{self, Write}, marker::PhantomData
+};
+
+mod content;
+
+use self::content::{Content, ContentRefDeserializer};
+
+/// Serialization of the active variant of a `SumN`, implemented for every arity whose types all implement `Serialize`.
+pub trait SerializeVariants {
+	/// The name of the active variant, `"A"`, `"B"`, etc.
+	fn variant_name(&self) -> &'static str;
+	/// The zero-based index of the active variant.
+	fn variant_index(&self) -> usize;
+	/// Serialize the active variant's value, without any tag.
+	fn serialize_variant<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer;
+}
+
+impl<T> SerializeVariants for &T
+where
+	T: SerializeVariants + ?Sized,
+{
+	fn variant_name(&self) -> &'static str {
+		(**self).variant_name()
+	}
+	fn variant_index(&self) -> usize {
+		(**self).variant_index()
+	}
+	fn serialize_variant<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		(**self).serialize_variant(serializer)
+	}
+}
+
+/// Deserialization of a given variant of a `SumN`, implemented for every arity whose types all implement `Deserialize`.
+pub trait DeserializeVariants<'de>: Sized {
+	/// The variant names, `"A"`, `"B"`, etc.
+	const VARIANTS: &'static [&'static str];
+	/// Deserialize the value of the variant with zero-based index `index`.
+	fn deserialize_variant<D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>;
+}
+
+/// The field names used by the [`adjacent`] and [`internal`] representations.
+pub trait Keys {
+	/// The field holding the variant name.
+	const TAG: &'static str;
+	/// The field holding the variant's value, used only by [`adjacent`].
+	const CONTENT: &'static str;
+	#[doc(hidden)]
+	const FIELDS: &'static [&'static str] = &[Self::TAG, Self::CONTENT];
+}
+
+/// The keys `"tag"` and `"content"`.
+#[derive(Copy, Clone, Debug)]
+pub enum DefaultKeys {}
+impl Keys for DefaultKeys {
+	const TAG: &'static str = "tag";
+	const CONTENT: &'static str = "content";
+}
+
+struct Variant<'a, T>(&'a T);
+impl<T> Serialize for Variant<'_, T>
+where
+	T: SerializeVariants,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.0.serialize_variant(serializer)
+	}
+}
+
+struct VariantSeed<T>(usize, PhantomData<fn() -> T>);
+impl<'de, T> DeserializeSeed<'de> for VariantSeed<T>
+where
+	T: DeserializeVariants<'de>,
+{
+	type Value = T;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<T, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		T::deserialize_variant(self.0, deserializer)
+	}
+}
+
+fn index_of<E>(variants: &'static [&'static str], name: &str) -> Result<usize, E>
+where
+	E: de::Error,
+{
+	variants
+		.iter()
+		.position(|variant| *variant == name)
+		.ok_or_else(|| E::unknown_variant(name, variants))
+}
+
+/// The value of the active variant, without any tag.
+///
+/// Deserializing tries each variant in order, returning the first that succeeds. The input is buffered once so that it can be replayed for each variant, borrowing from it where the deserializer allows, so variants such as `&'de str` deserialize too. If none succeed, the error lists why each variant was rejected:
+///
+/// ```text
+/// data did not match any variant of untagged sum: A: invalid type: string "x", expected u32; B: invalid type: string "x", expected a boolean
+/// ```
+pub mod untagged {
+	use super::*;
+
+	pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: SerializeVariants,
+		S: Serializer,
+	{
+		value.serialize_variant(serializer)
+	}
+
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: DeserializeVariants<'de>,
+		D: Deserializer<'de>,
+	{
+		let content = Content::deserialize(deserializer)?;
+		let mut message = String::from("data did not match any variant of untagged sum");
+		for (index, variant) in T::VARIANTS.iter().enumerate() {
+			match T::deserialize_variant(index, ContentRefDeserializer::<D::Error>::new(&content)) {
+				Ok(sum) => return Ok(sum),
+				Err(err) => {
+					let separator = if index == 0 { ':' } else { ';' };
+					let _ = write!(message, "{separator} {variant}: {err}");
+				}
+			}
+		}
+		Err(D::Error::custom(message))
+	}
+}
+
+/// Wraps a `SumN` to serialize and deserialize it with the [`untagged`] representation.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Untagged<T>(pub T);
+impl<T> Serialize for Untagged<T>
+where
+	T: SerializeVariants,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		untagged::serialize(&self.0, serializer)
+	}
+}
+impl<'de, T> Deserialize<'de> for Untagged<T>
+where
+	T: DeserializeVariants<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		untagged::deserialize(deserializer).map(Untagged)
+	}
+}
+
+/// A tuple of the zero-based variant index and its value, so `Sum2::<u32, String>::B("b".into())` is `[1,"b"]` in JSON.
+pub mod indexed {
+	use super::*;
+
+	pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: SerializeVariants,
+		S: Serializer,
+	{
+		let mut tuple = serializer.serialize_tuple(2)?;
+		tuple.serialize_element(&(value.variant_index() as u64))?;
+		tuple.serialize_element(&Variant(value))?;
+		tuple.end()
+	}
+
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: DeserializeVariants<'de>,
+		D: Deserializer<'de>,
+	{
+		struct IndexedVisitor<T>(PhantomData<fn() -> T>);
+		impl<'de, T> Visitor<'de> for IndexedVisitor<T>
+		where
+			T: DeserializeVariants<'de>,
+		{
+			type Value = T;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("a tuple of variant index and value")
+			}
+			fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+			where
+				A: SeqAccess<'de>,
+			{
+				let index: usize = seq
+					.next_element()?
+					.ok_or_else(|| A::Error::invalid_length(0, &self))?;
+				if index >= T::VARIANTS.len() {
+					return Err(A::Error::invalid_value(
+						Unexpected::Unsigned(index as u64),
+						&"a variant index",
+					));
+				}
+				seq.next_element_seed(VariantSeed(index, PhantomData))?
+					.ok_or_else(|| A::Error::invalid_length(1, &self))
+			}
+		}
+		deserializer.deserialize_tuple(2, IndexedVisitor(PhantomData))
+	}
+}
+
+/// A struct of the variant name and its value, so `Sum2::<u32, String>::A(5)` is `{"tag":"A","content":5}` in JSON.
+pub mod adjacent {
+	use super::*;
+
+	pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: SerializeVariants,
+		S: Serializer,
+	{
+		serialize_with_keys::<DefaultKeys, T, S>(value, serializer)
+	}
+
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: DeserializeVariants<'de>,
+		D: Deserializer<'de>,
+	{
+		deserialize_with_keys::<DefaultKeys, T, D>(deserializer)
+	}
+
+	pub fn serialize_with_keys<K, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		K: Keys,
+		T: SerializeVariants,
+		S: Serializer,
+	{
+		let mut state = serializer.serialize_struct("Sum", 2)?;
+		state.serialize_field(K::TAG, value.variant_name())?;
+		state.serialize_field(K::CONTENT, &Variant(value))?;
+		state.end()
+	}
+
+	pub fn deserialize_with_keys<'de, K, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		K: Keys,
+		T: DeserializeVariants<'de>,
+		D: Deserializer<'de>,
+	{
+		struct AdjacentVisitor<K, T>(PhantomData<fn() -> (K, T)>);
+		impl<'de, K, T> Visitor<'de> for AdjacentVisitor<K, T>
+		where
+			K: Keys,
+			T: DeserializeVariants<'de>,
+		{
+			type Value = T;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				write!(
+					formatter,
+					"a struct with fields {:?} and {:?}",
+					K::TAG,
+					K::CONTENT
+				)
+			}
+			fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+			where
+				A: SeqAccess<'de>,
+			{
+				let tag: String = seq
+					.next_element()?
+					.ok_or_else(|| A::Error::invalid_length(0, &self))?;
+				let index = index_of(T::VARIANTS, &tag)?;
+				seq.next_element_seed(VariantSeed(index, PhantomData))?
+					.ok_or_else(|| A::Error::invalid_length(1, &self))
+			}
+			fn visit_map<A>(self, mut map: A) -> Result<T, A::Error>
+			where
+				A: MapAccess<'de>,
+			{
+				let (mut index, mut content, mut sum) = (None, None, None);
+				while let Some(key) = map.next_key::<String>()? {
+					if key == K::TAG {
+						if index.is_some() {
+							return Err(A::Error::duplicate_field(K::TAG));
+						}
+						let tag: String = map.next_value()?;
+						let index_ = index_of(T::VARIANTS, &tag)?;
+						if let Some(content) = content.take() {
+							sum = Some(T::deserialize_variant(
+								index_,
+								ContentRefDeserializer::<A::Error>::new(&content),
+							)?);
+						}
+						index = Some(index_);
+					} else if key == K::CONTENT {
+						if content.is_some() || sum.is_some() {
+							return Err(A::Error::duplicate_field(K::CONTENT));
+						}
+						match index {
+							Some(index) => {
+								sum = Some(map.next_value_seed(VariantSeed(index, PhantomData))?);
+							}
+							None => content = Some(map.next_value::<Content<'de>>()?),
+						}
+					} else {
+						let _ = map.next_value::<IgnoredAny>()?;
+					}
+				}
+				sum.ok_or_else(|| {
+					A::Error::missing_field(if index.is_none() { K::TAG } else { K::CONTENT })
+				})
+			}
+		}
+		deserializer.deserialize_struct("Sum", K::FIELDS, AdjacentVisitor::<K, T>(PhantomData))
+	}
+}
+
+/// The variant name inserted as a field alongside the fields of its value, so `Sum2::<Point, Line>::A(Point { x: 1, y: 2 })` is `{"tag":"A","x":1,"y":2}` in JSON.
+///
+/// This requires that every variant's value serializes as a map or struct. As with serde's own internally tagged enums, the tag is written first, followed by the value's fields in their order. Deserializing buffers the input, borrowing from it where the deserializer allows.
+pub mod internal {
+	use super::*;
+
+	pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: SerializeVariants,
+		S: Serializer,
+	{
+		serialize_with_keys::<DefaultKeys, T, S>(value, serializer)
+	}
+
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: DeserializeVariants<'de>,
+		D: Deserializer<'de>,
+	{
+		deserialize_with_keys::<DefaultKeys, T, D>(deserializer)
+	}
+
+	pub fn serialize_with_keys<K, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		K: Keys,
+		T: SerializeVariants,
+		S: Serializer,
+	{
+		value.serialize_variant(TaggedSerializer {
+			serializer,
+			tag: K::TAG,
+			variant: value.variant_name(),
+		})
+	}
+
+	pub fn deserialize_with_keys<'de, K, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		K: Keys,
+		T: DeserializeVariants<'de>,
+		D: Deserializer<'de>,
+	{
+		let Content::Map(mut fields) = Content::deserialize(deserializer)? else {
+			return Err(D::Error::custom("expected an internally tagged map"));
+		};
+		let tag = fields
+			.iter()
+			.position(|(key, _)| key.as_str() == Some(K::TAG))
+			.ok_or_else(|| D::Error::missing_field(K::TAG))?;
+		let (_, tag) = fields.remove(tag);
+		let tag = tag
+			.as_str()
+			.ok_or_else(|| D::Error::custom("expected the tag to be a string"))?;
+		let index = index_of(T::VARIANTS, tag)?;
+		T::deserialize_variant(index, ContentRefDeserializer::<D::Error>::new(&Content::Map(fields)))
+	}
+
+	/// Serializes a map or struct with the tag as its first entry.
+	struct TaggedSerializer<S> {
+		serializer: S,
+		tag: &'static str,
+		variant: &'static str,
+	}
+	impl<S> TaggedSerializer<S>
+	where
+		S: Serializer,
+	{
+		fn unsupported(&self, holds: &str) -> S::Error {
+			S::Error::custom(format!(
+				"internally tagged sum can only hold maps or structs, but variant {} holds {holds}",
+				self.variant
+			))
+		}
+	}
+	impl<S> Serializer for TaggedSerializer<S>
+	where
+		S: Serializer,
+	{
+		type Ok = S::Ok;
+		type Error = S::Error;
+		type SerializeSeq = Impossible<S::Ok, S::Error>;
+		type SerializeTuple = Impossible<S::Ok, S::Error>;
+		type SerializeTupleStruct = Impossible<S::Ok, S::Error>;
+		type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+		type SerializeMap = S::SerializeMap;
+		type SerializeStruct = S::SerializeStruct;
+		type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+		fn serialize_bool(self, _: bool) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("a boolean"))
+		}
+		fn serialize_i8(self, _: i8) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_i16(self, _: i16) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_i32(self, _: i32) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_i64(self, _: i64) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_i128(self, _: i128) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_u8(self, _: u8) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_u16(self, _: u16) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_u32(self, _: u32) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_u64(self, _: u64) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_u128(self, _: u128) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an integer"))
+		}
+		fn serialize_f32(self, _: f32) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("a float"))
+		}
+		fn serialize_f64(self, _: f64) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("a float"))
+		}
+		fn serialize_char(self, _: char) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("a char"))
+		}
+		fn serialize_str(self, _: &str) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("a string"))
+		}
+		fn serialize_bytes(self, _: &[u8]) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("bytes"))
+		}
+		fn serialize_none(self) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an option"))
+		}
+		fn serialize_some<V>(self, _: &V) -> Result<S::Ok, S::Error>
+		where
+			V: Serialize + ?Sized,
+		{
+			Err(self.unsupported("an option"))
+		}
+		fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("a unit"))
+		}
+		fn serialize_unit_struct(self, _: &'static str) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("a unit struct"))
+		}
+		fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<S::Ok, S::Error> {
+			Err(self.unsupported("an enum"))
+		}
+		fn serialize_newtype_struct<V>(self, _: &'static str, value: &V) -> Result<S::Ok, S::Error>
+		where
+			V: Serialize + ?Sized,
+		{
+			value.serialize(self)
+		}
+		fn serialize_newtype_variant<V>(self, _: &'static str, _: u32, _: &'static str, _: &V) -> Result<S::Ok, S::Error>
+		where
+			V: Serialize + ?Sized,
+		{
+			Err(self.unsupported("an enum"))
+		}
+		fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+			Err(self.unsupported("a sequence"))
+		}
+		fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, S::Error> {
+			Err(self.unsupported("a tuple"))
+		}
+		fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeTupleStruct, S::Error> {
+			Err(self.unsupported("a tuple struct"))
+		}
+		fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeTupleVariant, S::Error> {
+			Err(self.unsupported("an enum"))
+		}
+		fn serialize_map(self, len: Option<usize>) -> Result<S::SerializeMap, S::Error> {
+			let mut map = self.serializer.serialize_map(len.map(|len| len + 1))?;
+			map.serialize_entry(self.tag, self.variant)?;
+			Ok(map)
+		}
+		fn serialize_struct(self, name: &'static str, len: usize) -> Result<S::SerializeStruct, S::Error> {
+			let mut state = self.serializer.serialize_struct(name, len + 1)?;
+			state.serialize_field(self.tag, self.variant)?;
+			Ok(state)
+		}
+		fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeStructVariant, S::Error> {
+			Err(self.unsupported("an enum"))
+		}
+	}
+}
@@ -0,0 +1,374 @@
+//! A buffer of any self-describing input, so that the [`untagged`](super::untagged), [`adjacent`](super::adjacent) and [`internal`](super::internal) representations can look ahead or replay it.
+//!
+//! Unlike an owned value tree, it keeps strings and bytes borrowed from the input where the deserializer allows, so variants such as `&'de str` still deserialize, and it keeps map entries in their input order.
+
+use ::serde::de::{
+	self, value::{MapDeserializer, SeqDeserializer}, Deserialize, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, Unexpected, VariantAccess, Visitor
+};
+use std::{fmt, marker::PhantomData};
+
+#[derive(Clone, Debug)]
+pub(super) enum Content<'de> {
+	Bool(bool),
+	U8(u8),
+	U16(u16),
+	U32(u32),
+	U64(u64),
+	U128(u128),
+	I8(i8),
+	I16(i16),
+	I32(i32),
+	I64(i64),
+	I128(i128),
+	F32(f32),
+	F64(f64),
+	Char(char),
+	String(String),
+	Str(&'de str),
+	ByteBuf(Vec<u8>),
+	Bytes(&'de [u8]),
+	None,
+	Some(Box<Content<'de>>),
+	Unit,
+	Newtype(Box<Content<'de>>),
+	Seq(Vec<Content<'de>>),
+	Map(Vec<(Content<'de>, Content<'de>)>),
+}
+
+impl Content<'_> {
+	pub(super) fn as_str(&self) -> Option<&str> {
+		match *self {
+			Content::String(ref string) => Some(string),
+			Content::Str(string) => Some(string),
+			_ => None,
+		}
+	}
+	fn unexpected(&self) -> Unexpected<'_> {
+		match *self {
+			Content::Bool(value) => Unexpected::Bool(value),
+			Content::U8(value) => Unexpected::Unsigned(value.into()),
+			Content::U16(value) => Unexpected::Unsigned(value.into()),
+			Content::U32(value) => Unexpected::Unsigned(value.into()),
+			Content::U64(value) => Unexpected::Unsigned(value),
+			Content::I8(value) => Unexpected::Signed(value.into()),
+			Content::I16(value) => Unexpected::Signed(value.into()),
+			Content::I32(value) => Unexpected::Signed(value.into()),
+			Content::I64(value) => Unexpected::Signed(value),
+			Content::U128(_) | Content::I128(_) => Unexpected::Other("128-bit integer"),
+			Content::F32(value) => Unexpected::Float(value.into()),
+			Content::F64(value) => Unexpected::Float(value),
+			Content::Char(value) => Unexpected::Char(value),
+			Content::String(ref value) => Unexpected::Str(value),
+			Content::Str(value) => Unexpected::Str(value),
+			Content::ByteBuf(ref value) => Unexpected::Bytes(value),
+			Content::Bytes(value) => Unexpected::Bytes(value),
+			Content::None | Content::Some(_) => Unexpected::Option,
+			Content::Unit => Unexpected::Unit,
+			Content::Newtype(_) => Unexpected::NewtypeStruct,
+			Content::Seq(_) => Unexpected::Seq,
+			Content::Map(_) => Unexpected::Map,
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any(ContentVisitor)
+	}
+}
+
+struct ContentVisitor;
+impl<'de> Visitor<'de> for ContentVisitor {
+	type Value = Content<'de>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("any value")
+	}
+	fn visit_bool<E>(self, value: bool) -> Result<Content<'de>, E> {
+		Ok(Content::Bool(value))
+	}
+	fn visit_u8<E>(self, value: u8) -> Result<Content<'de>, E> {
+		Ok(Content::U8(value))
+	}
+	fn visit_u16<E>(self, value: u16) -> Result<Content<'de>, E> {
+		Ok(Content::U16(value))
+	}
+	fn visit_u32<E>(self, value: u32) -> Result<Content<'de>, E> {
+		Ok(Content::U32(value))
+	}
+	fn visit_u64<E>(self, value: u64) -> Result<Content<'de>, E> {
+		Ok(Content::U64(value))
+	}
+	fn visit_u128<E>(self, value: u128) -> Result<Content<'de>, E> {
+		Ok(Content::U128(value))
+	}
+	fn visit_i8<E>(self, value: i8) -> Result<Content<'de>, E> {
+		Ok(Content::I8(value))
+	}
+	fn visit_i16<E>(self, value: i16) -> Result<Content<'de>, E> {
+		Ok(Content::I16(value))
+	}
+	fn visit_i32<E>(self, value: i32) -> Result<Content<'de>, E> {
+		Ok(Content::I32(value))
+	}
+	fn visit_i64<E>(self, value: i64) -> Result<Content<'de>, E> {
+		Ok(Content::I64(value))
+	}
+	fn visit_i128<E>(self, value: i128) -> Result<Content<'de>, E> {
+		Ok(Content::I128(value))
+	}
+	fn visit_f32<E>(self, value: f32) -> Result<Content<'de>, E> {
+		Ok(Content::F32(value))
+	}
+	fn visit_f64<E>(self, value: f64) -> Result<Content<'de>, E> {
+		Ok(Content::F64(value))
+	}
+	fn visit_char<E>(self, value: char) -> Result<Content<'de>, E> {
+		Ok(Content::Char(value))
+	}
+	fn visit_str<E>(self, value: &str) -> Result<Content<'de>, E> {
+		Ok(Content::String(value.to_owned()))
+	}
+	fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Content<'de>, E> {
+		Ok(Content::Str(value))
+	}
+	fn visit_string<E>(self, value: String) -> Result<Content<'de>, E> {
+		Ok(Content::String(value))
+	}
+	fn visit_bytes<E>(self, value: &[u8]) -> Result<Content<'de>, E> {
+		Ok(Content::ByteBuf(value.to_owned()))
+	}
+	fn visit_borrowed_bytes<E>(self, value: &'de [u8]) -> Result<Content<'de>, E> {
+		Ok(Content::Bytes(value))
+	}
+	fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Content<'de>, E> {
+		Ok(Content::ByteBuf(value))
+	}
+	fn visit_none<E>(self) -> Result<Content<'de>, E> {
+		Ok(Content::None)
+	}
+	fn visit_some<D>(self, deserializer: D) -> Result<Content<'de>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		Content::deserialize(deserializer).map(|content| Content::Some(Box::new(content)))
+	}
+	fn visit_unit<E>(self) -> Result<Content<'de>, E> {
+		Ok(Content::Unit)
+	}
+	fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Content<'de>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		Content::deserialize(deserializer).map(|content| Content::Newtype(Box::new(content)))
+	}
+	fn visit_seq<A>(self, mut seq: A) -> Result<Content<'de>, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+		while let Some(element) = seq.next_element()? {
+			elements.push(element);
+		}
+		Ok(Content::Seq(elements))
+	}
+	fn visit_map<A>(self, mut map: A) -> Result<Content<'de>, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+		while let Some(entry) = map.next_entry()? {
+			entries.push(entry);
+		}
+		Ok(Content::Map(entries))
+	}
+}
+
+/// Deserializes from a borrowed [`Content`], so that it can be replayed for each variant without cloning.
+pub(super) struct ContentRefDeserializer<'a, 'de, E> {
+	content: &'a Content<'de>,
+	marker: PhantomData<fn() -> E>,
+}
+impl<'a, 'de, E> ContentRefDeserializer<'a, 'de, E> {
+	pub(super) fn new(content: &'a Content<'de>) -> Self {
+		ContentRefDeserializer {
+			content,
+			marker: PhantomData,
+		}
+	}
+}
+impl<'a, 'de, E> IntoDeserializer<'de, E> for &'a Content<'de>
+where
+	E: de::Error,
+{
+	type Deserializer = ContentRefDeserializer<'a, 'de, E>;
+
+	fn into_deserializer(self) -> Self::Deserializer {
+		ContentRefDeserializer::new(self)
+	}
+}
+
+impl<'de, E> Deserializer<'de> for ContentRefDeserializer<'_, 'de, E>
+where
+	E: de::Error,
+{
+	type Error = E;
+
+	fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+	where
+		V: Visitor<'de>,
+	{
+		match *self.content {
+			Content::Bool(value) => visitor.visit_bool(value),
+			Content::U8(value) => visitor.visit_u8(value),
+			Content::U16(value) => visitor.visit_u16(value),
+			Content::U32(value) => visitor.visit_u32(value),
+			Content::U64(value) => visitor.visit_u64(value),
+			Content::U128(value) => visitor.visit_u128(value),
+			Content::I8(value) => visitor.visit_i8(value),
+			Content::I16(value) => visitor.visit_i16(value),
+			Content::I32(value) => visitor.visit_i32(value),
+			Content::I64(value) => visitor.visit_i64(value),
+			Content::I128(value) => visitor.visit_i128(value),
+			Content::F32(value) => visitor.visit_f32(value),
+			Content::F64(value) => visitor.visit_f64(value),
+			Content::Char(value) => visitor.visit_char(value),
+			Content::String(ref value) => visitor.visit_str(value),
+			Content::Str(value) => visitor.visit_borrowed_str(value),
+			Content::ByteBuf(ref value) => visitor.visit_bytes(value),
+			Content::Bytes(value) => visitor.visit_borrowed_bytes(value),
+			Content::None => visitor.visit_none(),
+			Content::Some(ref content) => visitor.visit_some(ContentRefDeserializer::new(content)),
+			Content::Unit => visitor.visit_unit(),
+			Content::Newtype(ref content) => visitor.visit_newtype_struct(ContentRefDeserializer::new(content)),
+			Content::Seq(ref elements) => {
+				let mut seq = SeqDeserializer::new(elements.iter());
+				let value = visitor.visit_seq(&mut seq)?;
+				seq.end()?;
+				Ok(value)
+			}
+			Content::Map(ref entries) => {
+				let mut map = MapDeserializer::new(entries.iter().map(|(key, value)| (key, value)));
+				let value = visitor.visit_map(&mut map)?;
+				map.end()?;
+				Ok(value)
+			}
+		}
+	}
+	fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+	where
+		V: Visitor<'de>,
+	{
+		match *self.content {
+			Content::None | Content::Unit => visitor.visit_none(),
+			Content::Some(ref content) => visitor.visit_some(ContentRefDeserializer::new(content)),
+			_ => visitor.visit_some(self),
+		}
+	}
+	fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+	where
+		V: Visitor<'de>,
+	{
+		match *self.content {
+			Content::Newtype(ref content) => visitor.visit_newtype_struct(ContentRefDeserializer::new(content)),
+			_ => visitor.visit_newtype_struct(self),
+		}
+	}
+	fn deserialize_enum<V>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, E>
+	where
+		V: Visitor<'de>,
+	{
+		let (variant, value) = match *self.content {
+			Content::String(_) | Content::Str(_) => (self.content, None),
+			Content::Map(ref entries) if entries.len() == 1 => (&entries[0].0, Some(&entries[0].1)),
+			Content::Map(_) => return Err(E::invalid_value(Unexpected::Map, &"a map with a single key")),
+			ref other => return Err(E::invalid_type(other.unexpected(), &"a string or a map")),
+		};
+		visitor.visit_enum(EnumRefDeserializer {
+			variant,
+			value,
+			marker: PhantomData,
+		})
+	}
+
+	::serde::forward_to_deserialize_any! {
+		bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+		bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+		identifier ignored_any
+	}
+}
+
+struct EnumRefDeserializer<'a, 'de, E> {
+	variant: &'a Content<'de>,
+	value: Option<&'a Content<'de>>,
+	marker: PhantomData<fn() -> E>,
+}
+impl<'a, 'de, E> EnumAccess<'de> for EnumRefDeserializer<'a, 'de, E>
+where
+	E: de::Error,
+{
+	type Error = E;
+	type Variant = VariantRefDeserializer<'a, 'de, E>;
+
+	fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), E>
+	where
+		V: DeserializeSeed<'de>,
+	{
+		let variant = seed.deserialize(ContentRefDeserializer::new(self.variant))?;
+		Ok((
+			variant,
+			VariantRefDeserializer {
+				value: self.value,
+				marker: PhantomData,
+			},
+		))
+	}
+}
+
+struct VariantRefDeserializer<'a, 'de, E> {
+	value: Option<&'a Content<'de>>,
+	marker: PhantomData<fn() -> E>,
+}
+impl<'de, E> VariantAccess<'de> for VariantRefDeserializer<'_, 'de, E>
+where
+	E: de::Error,
+{
+	type Error = E;
+
+	fn unit_variant(self) -> Result<(), E> {
+		match self.value {
+			Some(value) => <()>::deserialize(ContentRefDeserializer::new(value)),
+			None => Ok(()),
+		}
+	}
+	fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, E>
+	where
+		T: DeserializeSeed<'de>,
+	{
+		match self.value {
+			Some(value) => seed.deserialize(ContentRefDeserializer::new(value)),
+			None => Err(E::invalid_type(Unexpected::UnitVariant, &"a newtype variant")),
+		}
+	}
+	fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, E>
+	where
+		V: Visitor<'de>,
+	{
+		match self.value {
+			Some(value) => ContentRefDeserializer::new(value).deserialize_seq(visitor),
+			None => Err(E::invalid_type(Unexpected::UnitVariant, &"a tuple variant")),
+		}
+	}
+	fn struct_variant<V>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, E>
+	where
+		V: Visitor<'de>,
+	{
+		match self.value {
+			Some(value) => ContentRefDeserializer::new(value).deserialize_map(visitor),
+			None => Err(E::invalid_type(Unexpected::UnitVariant, &"a struct variant")),
+		}
+	}
+}
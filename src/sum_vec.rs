@@ -0,0 +1,70 @@
+//! `SumVecN`, which stores `SumN` values with each variant in a separate dense `Vec`.
+
+use std::{
+	fmt::{Debug, Formatter, Result}, iter::FromIterator
+};
+
+#[cfg(feature = "1")]
+impl_sum!(@vec SumVec1 Sum1: A a 0);
+#[cfg(feature = "2")]
+impl_sum!(@vec SumVec2 Sum2: A a 0 B b 1);
+#[cfg(feature = "3")]
+impl_sum!(@vec SumVec3 Sum3: A a 0 B b 1 C c 2);
+#[cfg(feature = "4")]
+impl_sum!(@vec SumVec4 Sum4: A a 0 B b 1 C c 2 D d 3);
+#[cfg(feature = "5")]
+impl_sum!(@vec SumVec5 Sum5: A a 0 B b 1 C c 2 D d 3 E e 4);
+#[cfg(feature = "6")]
+impl_sum!(@vec SumVec6 Sum6: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5);
+#[cfg(feature = "7")]
+impl_sum!(@vec SumVec7 Sum7: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6);
+#[cfg(feature = "8")]
+impl_sum!(@vec SumVec8 Sum8: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7);
+#[cfg(feature = "9")]
+impl_sum!(@vec SumVec9 Sum9: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8);
+#[cfg(feature = "10")]
+impl_sum!(@vec SumVec10 Sum10: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9);
+#[cfg(feature = "11")]
+impl_sum!(@vec SumVec11 Sum11: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10);
+#[cfg(feature = "12")]
+impl_sum!(@vec SumVec12 Sum12: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11);
+#[cfg(feature = "13")]
+impl_sum!(@vec SumVec13 Sum13: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12);
+#[cfg(feature = "14")]
+impl_sum!(@vec SumVec14 Sum14: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13);
+#[cfg(feature = "15")]
+impl_sum!(@vec SumVec15 Sum15: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14);
+#[cfg(feature = "16")]
+impl_sum!(@vec SumVec16 Sum16: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15);
+#[cfg(feature = "17")]
+impl_sum!(@vec SumVec17 Sum17: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16);
+#[cfg(feature = "18")]
+impl_sum!(@vec SumVec18 Sum18: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17);
+#[cfg(feature = "19")]
+impl_sum!(@vec SumVec19 Sum19: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18);
+#[cfg(feature = "20")]
+impl_sum!(@vec SumVec20 Sum20: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19);
+#[cfg(feature = "21")]
+impl_sum!(@vec SumVec21 Sum21: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20);
+#[cfg(feature = "22")]
+impl_sum!(@vec SumVec22 Sum22: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21);
+#[cfg(feature = "23")]
+impl_sum!(@vec SumVec23 Sum23: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22);
+#[cfg(feature = "24")]
+impl_sum!(@vec SumVec24 Sum24: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23);
+#[cfg(feature = "25")]
+impl_sum!(@vec SumVec25 Sum25: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24);
+#[cfg(feature = "26")]
+impl_sum!(@vec SumVec26 Sum26: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24 Z z 25);
+#[cfg(feature = "27")]
+impl_sum!(@vec SumVec27 Sum27: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24 Z z 25 Aa aa 26);
+#[cfg(feature = "28")]
+impl_sum!(@vec SumVec28 Sum28: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24 Z z 25 Aa aa 26 Ab ab 27);
+#[cfg(feature = "29")]
+impl_sum!(@vec SumVec29 Sum29: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24 Z z 25 Aa aa 26 Ab ab 27 Ac ac 28);
+#[cfg(feature = "30")]
+impl_sum!(@vec SumVec30 Sum30: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24 Z z 25 Aa aa 26 Ab ab 27 Ac ac 28 Ad ad 29);
+#[cfg(feature = "31")]
+impl_sum!(@vec SumVec31 Sum31: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24 Z z 25 Aa aa 26 Ab ab 27 Ac ac 28 Ad ad 29 Ae ae 30);
+#[cfg(feature = "32")]
+impl_sum!(@vec SumVec32 Sum32: A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 I i 8 J j 9 K k 10 L l 11 M m 12 N n 13 O o 14 P p 15 Q q 16 R r 17 S s 18 T t 19 U u 20 V v 21 W w 22 X x 23 Y y 24 Z z 25 Aa aa 26 Ab ab 27 Ac ac 28 Ad ad 29 Ae ae 30 Af af 31);
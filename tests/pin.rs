@@ -0,0 +1,252 @@
+//! Tests of the pin projections `as_pin_ref`, `as_pin_mut` and those built on them, with `!Unpin` variants.
+//!
+//! These avoid threads, timers and real I/O so they also run under Miri, which checks the projections for undefined behaviour:
+//!
+//! ```text
+//! cargo +nightly miri test --test pin
+//! ```
+
+use std::{
+	cell::RefCell, future::Future, marker::PhantomPinned, panic::{self, AssertUnwindSafe}, pin::Pin, ptr, rc::Rc, task::{Context, Poll, RawWaker, RawWakerVTable, Waker}
+};
+use sum::*;
+
+fn noop_waker() -> Waker {
+	fn clone(_: *const ()) -> RawWaker {
+		RawWaker::new(ptr::null(), &VTABLE)
+	}
+	fn noop(_: *const ()) {}
+	static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+	unsafe { Waker::from_raw(clone(ptr::null())) }
+}
+
+/// Polls `future` to completion, returning its output and how many times it returned `Pending`.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+	let waker = noop_waker();
+	let mut cx = Context::from_waker(&waker);
+	let mut future = Box::pin(future);
+	let mut pending = 0;
+	loop {
+		match future.as_mut().poll(&mut cx) {
+			Poll::Ready(output) => return (output, pending),
+			Poll::Pending => pending += 1,
+		}
+	}
+}
+
+/// Returns `Pending` once, so that futures awaiting it are suspended with their borrows live.
+#[derive(Default)]
+struct YieldNow(bool);
+impl Future for YieldNow {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+		if self.0 {
+			Poll::Ready(())
+		} else {
+			self.0 = true;
+			cx.waker().wake_by_ref();
+			Poll::Pending
+		}
+	}
+}
+
+/// An `async` block that holds a borrow of its own local across a suspension point, making it self-referential.
+async fn self_referential(values: Vec<u32>) -> u32 {
+	let borrowed = &values;
+	YieldNow::default().await;
+	let first = borrowed.iter().sum::<u32>();
+	YieldNow::default().await;
+	first + borrowed.len() as u32
+}
+
+/// A `!Unpin` future that fails if it's moved between polls.
+struct AddressCheck {
+	address: Option<usize>,
+	polls: usize,
+	_pinned: PhantomPinned,
+}
+impl AddressCheck {
+	fn new(polls: usize) -> Self {
+		AddressCheck {
+			address: None,
+			polls,
+			_pinned: PhantomPinned,
+		}
+	}
+}
+impl Future for AddressCheck {
+	type Output = usize;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<usize> {
+		let this = unsafe { self.get_unchecked_mut() };
+		let address = ptr::addr_of!(*this) as usize;
+		assert_eq!(*this.address.get_or_insert(address), address, "pinned future was moved");
+		if this.polls == 0 {
+			Poll::Ready(address)
+		} else {
+			this.polls -= 1;
+			cx.waker().wake_by_ref();
+			Poll::Pending
+		}
+	}
+}
+
+/// Records its drop into a shared log.
+struct Recorder {
+	name: &'static str,
+	log: Rc<RefCell<Vec<&'static str>>>,
+}
+impl Drop for Recorder {
+	fn drop(&mut self) {
+		self.log.borrow_mut().push(self.name);
+	}
+}
+
+#[cfg(feature = "3")]
+#[test]
+fn self_referential_futures() {
+	let sums: Vec<Sum2<_, _>> = vec![Sum2::A(self_referential(vec![1, 2, 3])), Sum2::B(async { 5_u32 })];
+	let outputs: Vec<(u32, usize)> = sums.into_iter().map(block_on).collect();
+	assert_eq!(outputs, [(9, 2), (5, 0)]);
+
+	type Ready = std::future::Ready<u32>;
+	let (output, pending) = block_on(Sum3::<_, Ready, Ready>::A(self_referential(vec![4; 4])));
+	assert_eq!((output, pending), (20, 2));
+
+	let addressed = async {
+		let address = AddressCheck::new(3).await;
+		assert_ne!(address, 0);
+		7_u32
+	};
+	let (output, pending) = block_on(Sum3::<Ready, _, Ready>::B(addressed));
+	assert_eq!((output, pending), (7, 3));
+}
+
+#[cfg(feature = "2")]
+#[test]
+fn address_stability() {
+	macro_rules! check {
+		($sum:expr, $as_pin_ref:ident) => {{
+			let waker = noop_waker();
+			let mut cx = Context::from_waker(&waker);
+			let mut sum = Box::pin($sum);
+			assert!(sum.as_mut().poll(&mut cx).is_pending());
+			let address = ptr::addr_of!(*sum.as_ref().$as_pin_ref().unwrap()) as usize;
+			assert!(sum.as_mut().poll(&mut cx).is_pending());
+			assert_eq!(sum.as_mut().poll(&mut cx), Poll::Ready(address));
+		}};
+	}
+	type P = std::future::Pending<usize>;
+	check!(Sum1::A(AddressCheck::new(2)), as_pin_ref_a);
+	check!(Sum2::<P, _>::B(AddressCheck::new(2)), as_pin_ref_b);
+	#[cfg(feature = "5")]
+	check!(Sum5::<P, P, _, P, P>::C(AddressCheck::new(2)), as_pin_ref_c);
+	#[cfg(feature = "8")]
+	check!(Sum8::<P, P, P, P, P, P, P, _>::H(AddressCheck::new(2)), as_pin_ref_h);
+	#[cfg(feature = "32")]
+	check!(Sum32::<P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, _>::Af(AddressCheck::new(2)), as_pin_ref_af);
+}
+
+#[test]
+fn cons_futures() {
+	fn nested<F: Future<Output = u32>>(future: F) -> Cons![std::future::Ready<u32>, F] {
+		Cons::Tail(Cons::Head(future))
+	}
+	let (output, pending) = block_on(nested(self_referential(vec![1, 1])));
+	assert_eq!((output, pending), (4, 2));
+	let single: Cons![AddressCheck] = Cons::Head(AddressCheck::new(1));
+	let (output, pending) = block_on(single);
+	assert_ne!(output, 0);
+	assert_eq!(pending, 1);
+}
+
+#[cfg(feature = "3")]
+#[test]
+fn drop_order() {
+	let log = Rc::new(RefCell::new(Vec::new()));
+	let recorder = |name| Recorder { name, log: log.clone() };
+
+	let sum = Box::pin(Sum3::<Recorder, Recorder, Recorder>::B(recorder("b")));
+	assert!(log.borrow().is_empty());
+	drop(sum);
+	assert_eq!(*log.borrow(), ["b"]);
+
+	let mut sum = Box::pin(Sum2::<Recorder, Recorder>::A(recorder("a")));
+	sum.as_mut().set_b(recorder("replacement b"));
+	assert_eq!(*log.borrow(), ["b", "a"]);
+	sum.as_mut().set_a(recorder("replacement a"));
+	assert_eq!(*log.borrow(), ["b", "a", "replacement b"]);
+	assert_eq!(sum.as_ref().as_pin_ref_a().map(|a| a.name), Some("replacement a"));
+	drop(sum);
+	assert_eq!(*log.borrow(), ["b", "a", "replacement b", "replacement a"]);
+}
+
+#[cfg(feature = "2")]
+#[test]
+fn panic_safety() {
+	let log = Rc::new(RefCell::new(Vec::new()));
+	let held = Recorder { name: "held", log: log.clone() };
+	let mut sum = Box::pin(Sum2::<std::future::Ready<()>, _>::B(async move {
+		let held = &held;
+		YieldNow::default().await;
+		panic!("{} future panicked", held.name);
+	}));
+	let waker = noop_waker();
+	let mut cx = Context::from_waker(&waker);
+	assert!(sum.as_mut().poll(&mut cx).is_pending());
+	let result = panic::catch_unwind(AssertUnwindSafe(|| sum.as_mut().poll(&mut cx)));
+	assert!(result.is_err());
+	// Unwinding out of the `async` block dropped what it held.
+	assert_eq!(*log.borrow(), ["held"]);
+	// The panicked future is still safe to project to and drop, without dropping anything twice.
+	assert!(sum.as_mut().as_pin_mut_a().is_none());
+	assert!(sum.as_mut().as_pin_mut_b().is_some());
+	drop(sum);
+	assert_eq!(*log.borrow(), ["held"]);
+}
+
+#[cfg(all(feature = "futures", feature = "4"))]
+#[test]
+fn streams() {
+	use futures_core::Stream;
+
+	/// A `!Unpin` stream of the numbers below `end`, that fails if it's moved between polls.
+	struct Count {
+		next: u32,
+		end: u32,
+		address: Option<usize>,
+		_pinned: PhantomPinned,
+	}
+	impl Stream for Count {
+		type Item = u32;
+
+		fn poll_next(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Option<u32>> {
+			let this = unsafe { self.get_unchecked_mut() };
+			let address = ptr::addr_of!(*this) as usize;
+			assert_eq!(*this.address.get_or_insert(address), address, "pinned stream was moved");
+			if this.next == this.end {
+				return Poll::Ready(None);
+			}
+			this.next += 1;
+			Poll::Ready(Some(this.next - 1))
+		}
+	}
+	fn count(end: u32) -> Count {
+		Count { next: 0, end, address: None, _pinned: PhantomPinned }
+	}
+	fn collect<S: Stream>(stream: S) -> Vec<S::Item> {
+		let waker = noop_waker();
+		let mut cx = Context::from_waker(&waker);
+		let mut stream = Box::pin(stream);
+		let mut items = Vec::new();
+		while let Poll::Ready(Some(item)) = stream.as_mut().poll_next(&mut cx) {
+			items.push(item);
+		}
+		items
+	}
+	assert_eq!(collect(Sum2::<Count, Count>::B(count(3))), [0, 1, 2]);
+	assert_eq!(collect(Sum4::<Count, Count, Count, Count>::D(count(2))), [0, 1]);
+	let nested: Cons![Count, Count] = Cons::Tail(Cons::Head(count(1)));
+	assert_eq!(collect(nested), [0]);
+}